@@ -20,3 +20,164 @@ pub fn non_empty_nodeid<'de, D: Deserializer<'de>>(d: D) -> Result<Option<NodeId
     let o: Option<String> = Option::deserialize(d)?;
     Ok(o.filter(|s| !s.is_empty()).map(NodeId))
 }
+
+// Some endpoints return numbers as strings, depending on the node version
+pub fn number_or_str<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr + serde::Deserialize<'de>,
+    T::Err: std::fmt::Display,
+{
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrStr<T> {
+        Number(T),
+        Str(String),
+    }
+
+    match NumberOrStr::<T>::deserialize(d)? {
+        NumberOrStr::Number(n) => Ok(n),
+        NumberOrStr::Str(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+pub fn option_number_or_str<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr + serde::Deserialize<'de>,
+    T::Err: std::fmt::Display,
+{
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrStr<T> {
+        Number(T),
+        Str(String),
+    }
+
+    match Option::<NumberOrStr<T>>::deserialize(d)? {
+        Some(NumberOrStr::Number(n)) => Ok(Some(n)),
+        Some(NumberOrStr::Str(s)) if s.is_empty() => Ok(None),
+        Some(NumberOrStr::Str(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum IntOrBool {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+}
+
+impl IntOrBool {
+    fn into_bool(self) -> bool {
+        match self {
+            IntOrBool::Bool(b) => b,
+            IntOrBool::Int(i) => i != 0,
+            IntOrBool::Str(s) => !s.is_empty() && s != "0",
+        }
+    }
+}
+
+// Proxmox encodes booleans as 0/1 (or an empty string for false)
+pub fn int_bool<'de, D: Deserializer<'de>>(d: D) -> Result<bool, D::Error> {
+    use serde::Deserialize;
+    Ok(IntOrBool::deserialize(d)?.into_bool())
+}
+
+pub fn option_int_bool<'de, D: Deserializer<'de>>(d: D) -> Result<Option<bool>, D::Error> {
+    use serde::Deserialize;
+    let o: Option<IntOrBool> = Option::deserialize(d)?;
+    Ok(o.map(IntOrBool::into_bool))
+}
+
+pub fn non_empty_parsed<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    non_empty_str(d)?
+        .map(|s| s.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+// Numbers that Proxmox replaces with a placeholder like "N/A" when unknown
+pub fn lenient_number<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr + serde::Deserialize<'de>,
+{
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrStr<T> {
+        Number(T),
+        Str(String),
+    }
+
+    Ok(match Option::<NumberOrStr<T>>::deserialize(d)? {
+        Some(NumberOrStr::Number(n)) => Some(n),
+        Some(NumberOrStr::Str(s)) => s.parse().ok(),
+        None => None,
+    })
+}
+
+// Hardware ids like "0x8086" or "1d6b", with or without prefix
+pub fn hex_number<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64>,
+{
+    use serde::Deserialize;
+    parse_hex(&String::deserialize(d)?)
+}
+
+pub fn option_hex_number<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64>,
+{
+    non_empty_str(d)?.map(|s| parse_hex(&s)).transpose()
+}
+
+fn parse_hex<E: serde::de::Error, T: TryFrom<u64>>(s: &str) -> Result<T, E> {
+    let value = u64::from_str_radix(s.trim_start_matches("0x"), 16).map_err(E::custom)?;
+    T::try_from(value).map_err(|_| E::custom(format!("{s} is out of range")))
+}
+
+// Comma separated guest lists like "100,101"
+pub fn vmid_list<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<VMId>, D::Error> {
+    Ok(non_empty_str(d)?
+        .map(|s| {
+            s.split(',')
+                .map(str::trim)
+                .filter(|x| !x.is_empty())
+                .map(VMId::from)
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+pub fn comma_separated<'de, D, T>(d: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    non_empty_str(d)?
+        .map(|s| {
+            s.split(',')
+                .map(str::trim)
+                .filter(|x| !x.is_empty())
+                .map(|x| x.parse().map_err(serde::de::Error::custom))
+                .collect()
+        })
+        .unwrap_or(Ok(Vec::new()))
+}
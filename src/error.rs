@@ -1,6 +1,8 @@
 use reqwest::StatusCode;
 use thiserror::Error;
 
+use crate::model::node::{lxc::feature::Feature, VMId};
+
 #[derive(Error, Debug)]
 pub enum ProxmoxAPIError {
     #[error("Network Error")]
@@ -9,6 +11,17 @@ pub enum ProxmoxAPIError {
     DeserializationError,
     #[error("Unauthorized")]
     Unauthorized,
+    #[cfg(feature = "websocket")]
+    #[error("WebSocket Error")]
+    WebSocketError,
+    #[error("Invalid parameter: {0}")]
+    InvalidParameter(String),
+    #[error("Guest {0} is not a template, linked clones are only possible from templates")]
+    NotATemplate(VMId),
+    #[error("{1} is not supported by the storage of guest {0}")]
+    FeatureUnavailable(VMId, Feature),
+    #[error("No container backup found for guest {0}")]
+    NoBackup(VMId),
     #[error("Unknown API error")]
     ApiError(StatusCode),
 }
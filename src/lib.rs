@@ -16,6 +16,8 @@ mod deserializers;
 pub mod error;
 pub mod model;
 mod namespaces;
+#[cfg(feature = "websocket")]
+pub mod xterm;
 
 #[derive(Clone)]
 pub struct ProxmoxClient {
@@ -0,0 +1,241 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AcmeAccountName {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AcmeAccount {
+    #[serde(default)]
+    pub account: Option<AccountData>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub directory: Option<String>, // URL of ACME CA directory endpoint.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub location: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub tos: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccountData {
+    #[serde(default)]
+    pub contact: Vec<String>, // Contact URIs, e.g. 'mailto:admin@example.com'.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub status: Option<String>, // 'valid', 'deactivated' or 'revoked'.
+    #[serde(
+        default,
+        rename = "createdAt",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub created_at: Option<String>,
+}
+
+/// Parameters to register a new ACME account.
+#[derive(Serialize, Debug, Clone)]
+pub struct AccountRegistration {
+    pub contact: String, // Contact email addresses, comma separated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>, // ACME account config file name. Defaults to 'default'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>, // URL of ACME CA directory endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_url: Option<String>, // URL of CA TermsOfService - setting this indicates agreement.
+    #[serde(rename = "eab-kid", skip_serializing_if = "Option::is_none")]
+    pub eab_kid: Option<String>, // Key Identifier for External Account Binding.
+    #[serde(rename = "eab-hmac-key", skip_serializing_if = "Option::is_none")]
+    pub eab_hmac_key: Option<String>, // HMAC key for External Account Binding.
+}
+
+impl AccountRegistration {
+    pub fn new(contact: impl Into<String>) -> Self {
+        Self {
+            contact: contact.into(),
+            name: None,
+            directory: None,
+            tos_url: None,
+            eab_kid: None,
+            eab_hmac_key: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AcmeDirectory {
+    pub name: String,
+    pub url: String, // URL of ACME CA directory endpoint.
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AcmeMeta {
+    #[serde(default, rename = "caaIdentities")]
+    pub caa_identities: Vec<String>, // Hostnames referring to the ACME servers.
+    #[serde(
+        default,
+        rename = "externalAccountRequired",
+        deserialize_with = "crate::deserializers::int_bool"
+    )]
+    pub external_account_required: bool, // EAB Required
+    #[serde(
+        default,
+        rename = "termsOfService",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub terms_of_service: Option<String>, // ACME TermsOfService URL.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub website: Option<String>, // URL to more information about the ACME server.
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChallengeSchema {
+    pub id: String,
+    pub name: String, // Human readable name, falls back to id
+    #[serde(rename = "type")]
+    pub plugin_type: String,
+    pub schema: serde_json::Value, // API fields expected by the plugin.
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginType {
+    Dns,
+    Standalone,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AcmePlugin {
+    pub plugin: String, // Unique identifier for ACME plugin instance.
+    #[serde(rename = "type")]
+    pub plugin_type: PluginType,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub api: Option<String>, // API plugin name, e.g. 'cf'.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_parsed")]
+    pub data: Option<PluginData>, // DNS plugin data.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub disable: Option<bool>, // Flag to disable the config.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub nodes: Option<String>, // List of cluster node names.
+    #[serde(default, rename = "validation-delay")]
+    pub validation_delay: Option<u64>, // Extra delay in seconds to wait before requesting validation.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+}
+
+/// Parameters to add or update an ACME plugin.
+#[derive(Serialize, Debug, Clone)]
+pub struct PluginParameters {
+    pub id: String, // ACME Plugin ID name
+    #[serde(rename = "type")]
+    pub plugin_type: PluginType, // ACME challenge type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api: Option<String>, // API plugin name, e.g. 'cf'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<PluginData>, // DNS plugin data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable: Option<bool>, // Flag to disable the config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<String>, // List of cluster node names.
+    #[serde(rename = "validation-delay", skip_serializing_if = "Option::is_none")]
+    pub validation_delay: Option<u64>, // Extra delay in seconds to wait before requesting validation.
+}
+
+impl PluginParameters {
+    /// DNS challenge plugin using the given acme.sh API, e.g. `cf` for Cloudflare.
+    pub fn dns(id: impl Into<String>, api: impl Into<String>, data: PluginData) -> Self {
+        Self {
+            id: id.into(),
+            plugin_type: PluginType::Dns,
+            api: Some(api.into()),
+            data: Some(data),
+            disable: None,
+            nodes: None,
+            validation_delay: None,
+        }
+    }
+}
+
+/// Plugin settings that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PluginField {
+    Api,
+    Data,
+    Disable,
+    Nodes,
+    ValidationDelay,
+}
+
+impl Display for PluginField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Api => write!(f, "api"),
+            Self::Data => write!(f, "data"),
+            Self::Disable => write!(f, "disable"),
+            Self::Nodes => write!(f, "nodes"),
+            Self::ValidationDelay => write!(f, "validation-delay"),
+        }
+    }
+}
+
+/// Key/value settings of a DNS plugin, e.g. `CF_Token`.
+///
+/// Proxmox returns them as `KEY=value` lines, but expects them base64 encoded when writing.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PluginData(pub BTreeMap<String, String>);
+
+impl PluginData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    fn to_lines(&self) -> String {
+        self.0.iter().map(|(k, v)| format!("{k}={v}\n")).collect()
+    }
+}
+
+impl std::str::FromStr for PluginData {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_once('=')
+                    .map(|(k, v)| (k.trim().to_string(), v.to_string()))
+                    .ok_or_else(|| format!("Invalid plugin data line '{line}'"))
+            })
+            .collect::<Result<_, _>>()
+            .map(PluginData)
+    }
+}
+
+impl Serialize for PluginData {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::engine::general_purpose::STANDARD.encode(self.to_lines()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginData;
+
+    #[test]
+    pub fn test_plugin_data() {
+        let data: PluginData = "CF_Token=abc=\nCF_Account_ID=123\n".parse().unwrap();
+
+        assert_eq!(data.0["CF_Token"], "abc=");
+        assert_eq!(data.0["CF_Account_ID"], "123");
+
+        let data = PluginData::new().with("CF_Token", "abc");
+        assert_eq!(serde_json::to_value(&data).unwrap(), "Q0ZfVG9rZW49YWJjCg==");
+
+        assert!("garbage".parse::<PluginData>().is_err());
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::node::{
+    storage::GuestType,
+    vzdump::{
+        defaults::{Compression, Mode, NotificationPolicy},
+        prune::PruneBackups,
+    },
+    NodeId, VMId,
+};
+
+/// A scheduled backup job. Used both to read jobs and to create or update them.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct BackupJob {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>, // Job ID (will be autogenerated).
+    pub schedule: String, // Backup schedule. The format is a subset of `systemd` calendar events.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub enabled: Option<bool>, // Enable or disable the job.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "crate::model::node::vzdump::backup::comma_list",
+        deserialize_with = "crate::deserializers::vmid_list"
+    )]
+    pub vmid: Vec<VMId>, // The ID of the guest system you want to backup.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub all: Option<bool>, // Backup all known guest systems on this host.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "crate::model::node::vzdump::backup::comma_list",
+        deserialize_with = "crate::deserializers::vmid_list"
+    )]
+    pub exclude: Vec<VMId>, // Exclude specified guest systems (assumes --all)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub pool: Option<String>, // Backup all known guest systems included in the specified pool.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_nodeid"
+    )]
+    pub node: Option<NodeId>, // Only run if executed on this node.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub storage: Option<String>, // Store resulting file to this storage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>, // Backup mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compress: Option<Compression>, // Compress dump file.
+    #[serde(
+        default,
+        rename = "prune-backups",
+        skip_serializing_if = "crate::model::node::vzdump::prune::is_unset"
+    )]
+    pub prune_backups: Option<PruneBackups>, // Use these retention options instead of those from the storage configuration.
+    #[serde(
+        default,
+        rename = "notes-template",
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub notes_template: Option<String>, // Template string for generating notes for the backup(s).
+    #[serde(
+        default,
+        rename = "notification-policy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub notification_policy: Option<NotificationPolicy>, // Specify when to send a notification
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub mailto: Option<String>, // Comma-separated list of email addresses or users that should receive email notifications.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub comment: Option<String>, // Description for the Job.
+    #[serde(
+        default,
+        rename = "repeat-missed",
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub repeat_missed: Option<bool>, // If true, the job will be run as soon as possible if it was missed while the scheduler was not running.
+    #[serde(default, rename = "next-run", skip_serializing)]
+    pub next_run: Option<i64>, // Only returned when listing jobs.
+}
+
+impl BackupJob {
+    pub fn new(schedule: impl Into<String>) -> Self {
+        Self {
+            schedule: schedule.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Backup job settings that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackupJobField {
+    Enabled,
+    Vmid,
+    All,
+    Exclude,
+    Pool,
+    Node,
+    Storage,
+    Mode,
+    Compress,
+    PruneBackups,
+    NotesTemplate,
+    NotificationPolicy,
+    Mailto,
+    Comment,
+    RepeatMissed,
+}
+
+impl Display for BackupJobField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Enabled => write!(f, "enabled"),
+            Self::Vmid => write!(f, "vmid"),
+            Self::All => write!(f, "all"),
+            Self::Exclude => write!(f, "exclude"),
+            Self::Pool => write!(f, "pool"),
+            Self::Node => write!(f, "node"),
+            Self::Storage => write!(f, "storage"),
+            Self::Mode => write!(f, "mode"),
+            Self::Compress => write!(f, "compress"),
+            Self::PruneBackups => write!(f, "prune-backups"),
+            Self::NotesTemplate => write!(f, "notes-template"),
+            Self::NotificationPolicy => write!(f, "notification-policy"),
+            Self::Mailto => write!(f, "mailto"),
+            Self::Comment => write!(f, "comment"),
+            Self::RepeatMissed => write!(f, "repeat-missed"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IncludedVolumes {
+    pub children: Vec<IncludedGuest>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IncludedGuest {
+    pub id: u64, // VMID of the guest.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub name: Option<String>, // Name of the guest
+    #[serde(rename = "type")]
+    pub guest_type: GuestType, // Type of the guest, VM, CT or unknown for removed but not purged guests.
+    #[serde(default)]
+    pub children: Vec<IncludedVolume>, // The volumes of the guest with the information if they will be included in backups.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IncludedVolume {
+    pub id: String,   // Configuration key of the volume.
+    pub name: String, // Name of the volume.
+    #[serde(deserialize_with = "crate::deserializers::int_bool")]
+    pub included: bool, // Whether the volume is included in the backup or not.
+    pub reason: String, // The reason why the volume is included (or excluded).
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NotBackedUp {
+    pub vmid: u64, // VMID of the guest.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub name: Option<String>, // Name of the guest
+    #[serde(rename = "type")]
+    pub guest_type: GuestType, // Type of the guest.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BackupJob;
+    use crate::model::node::{vzdump::defaults::Mode, VMId};
+
+    #[test]
+    pub fn test_backup_job() {
+        let job: BackupJob = serde_json::from_str(
+            r#"{
+                "id": "backup-1f3e4c0a-5b2d",
+                "type": "vzdump",
+                "schedule": "sat 02:00",
+                "enabled": 1,
+                "vmid": "100,101",
+                "storage": "pbs",
+                "mode": "snapshot",
+                "prune-backups": "keep-last=3",
+                "next-run": 1704513600
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(job.vmid, vec![VMId::from("100"), VMId::from("101")]);
+        assert_eq!(job.enabled, Some(true));
+        assert_eq!(job.mode, Some(Mode::Snapshot));
+        assert_eq!(job.prune_backups.as_ref().unwrap().keep_last, Some(3));
+
+        let body = serde_json::to_value(&job).unwrap();
+        assert_eq!(body["vmid"], "100,101");
+        assert_eq!(body["prune-backups"], "keep-last=3");
+        assert!(body.get("next-run").is_none());
+    }
+}
@@ -0,0 +1,350 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::node::{NodeId, VMId};
+
+/// HA resource ID, encoded by Proxmox as `<type>:<vmid>`, e.g. `ct:100`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "String", into = "String")]
+pub enum HaResourceId {
+    Container(VMId),
+    VirtualMachine(VMId),
+}
+
+impl FromStr for HaResourceId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("ct", vmid)) => Ok(Self::Container(VMId::from(vmid))),
+            Some(("vm", vmid)) => Ok(Self::VirtualMachine(VMId::from(vmid))),
+            // The type defaults to vm when only the VMID is given
+            None if !s.is_empty() => Ok(Self::VirtualMachine(VMId::from(s))),
+            _ => Err(format!("Invalid HA resource ID '{s}'")),
+        }
+    }
+}
+
+impl TryFrom<String> for HaResourceId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for HaResourceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Container(vmid) => write!(f, "ct:{vmid}"),
+            Self::VirtualMachine(vmid) => write!(f, "vm:{vmid}"),
+        }
+    }
+}
+
+impl From<HaResourceId> for String {
+    fn from(value: HaResourceId) -> Self {
+        value.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HaResourceType {
+    #[serde(rename = "ct")]
+    Container,
+    #[serde(rename = "vm")]
+    VirtualMachine,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HaState {
+    Started,
+    Stopped,
+    Enabled, // Alias for started.
+    Disabled,
+    Ignored,
+}
+
+/// A guest managed by HA. Used both to read resources and to add or update them.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HaResource {
+    pub sid: HaResourceId, // HA resource ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<HaState>, // Requested resource state.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub group: Option<String>, // The HA group identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_restart: Option<u32>, // Maximal number of tries to restart the service on a node after its start failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_relocate: Option<u32>, // Maximal number of service relocate tries when a service failes to start.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub comment: Option<String>, // Description.
+    #[serde(default, skip_serializing)]
+    pub digest: Option<String>, // Can be used to prevent concurrent modifications.
+}
+
+impl HaResource {
+    pub fn new(sid: HaResourceId) -> Self {
+        Self {
+            sid,
+            state: None,
+            group: None,
+            max_restart: None,
+            max_relocate: None,
+            comment: None,
+            digest: None,
+        }
+    }
+}
+
+/// Resource properties that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HaResourceField {
+    State,
+    Group,
+    MaxRestart,
+    MaxRelocate,
+    Comment,
+}
+
+impl Display for HaResourceField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::State => write!(f, "state"),
+            Self::Group => write!(f, "group"),
+            Self::MaxRestart => write!(f, "max_restart"),
+            Self::MaxRelocate => write!(f, "max_relocate"),
+            Self::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+/// A group member, encoded by Proxmox as `<node>[:<priority>]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HaGroupNode {
+    pub node: NodeId,
+    pub priority: Option<u32>, // Nodes with higher priority are preferred.
+}
+
+impl FromStr for HaGroupNode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((node, priority)) => Ok(Self {
+                node: NodeId::from(node),
+                priority: Some(
+                    priority
+                        .parse()
+                        .map_err(|_| format!("Invalid node priority in '{s}'"))?,
+                ),
+            }),
+            None => Ok(Self {
+                node: NodeId::from(s),
+                priority: None,
+            }),
+        }
+    }
+}
+
+impl Display for HaGroupNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.node)?;
+        if let Some(priority) = self.priority {
+            write!(f, ":{priority}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An HA group. Used both to read groups and to create or update them.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HaGroup {
+    pub group: String, // The HA group identifier.
+    #[serde(
+        serialize_with = "crate::model::node::vzdump::backup::comma_list",
+        deserialize_with = "crate::deserializers::comma_separated"
+    )]
+    pub nodes: Vec<HaGroupNode>, // List of cluster node names with optional priority.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub restricted: Option<bool>, // Resources bound to restricted groups may only run on nodes defined by the group.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub nofailback: Option<bool>, // The CRM tries to run services on the node with the highest priority. If set, this doesn't happen when a node with higher priority comes online.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub comment: Option<String>, // Description.
+    #[serde(default, skip_serializing)]
+    pub digest: Option<String>, // Can be used to prevent concurrent modifications.
+}
+
+impl HaGroup {
+    pub fn new(group: impl Into<String>, nodes: Vec<HaGroupNode>) -> Self {
+        Self {
+            group: group.into(),
+            nodes,
+            restricted: None,
+            nofailback: None,
+            comment: None,
+            digest: None,
+        }
+    }
+}
+
+/// Group properties that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HaGroupField {
+    Restricted,
+    NoFailback,
+    Comment,
+}
+
+impl Display for HaGroupField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Restricted => write!(f, "restricted"),
+            Self::NoFailback => write!(f, "nofailback"),
+            Self::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HaStatus {
+    pub id: String, // Status entry ID (quorum, master, lrm:<node>, service:<sid>).
+    #[serde(rename = "type")]
+    pub status_type: HaStatusType,
+    pub status: String, // Status of the entry (value depends on type).
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_nodeid")]
+    pub node: Option<NodeId>, // Node associated to status entry.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub quorate: Option<bool>, // For type 'quorum'. Whether the cluster is quorate or not.
+    #[serde(default)]
+    pub timestamp: Option<i64>, // For type 'lrm','master'. Timestamp of the status information.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub crm_state: Option<String>, // For type 'service'. Service state as seen by the CRM.
+    #[serde(default)]
+    pub request_state: Option<HaState>, // For type 'service'. Requested service state.
+    #[serde(default)]
+    pub sid: Option<HaResourceId>, // For type 'service'. Service ID.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub state: Option<String>, // For type 'service'. Verbose service state.
+    #[serde(default)]
+    pub max_restart: Option<u32>, // For type 'service'.
+    #[serde(default)]
+    pub max_relocate: Option<u32>, // For type 'service'.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub group: Option<String>, // For type 'service'.
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HaStatusType {
+    Quorum,
+    Master,
+    Lrm,
+    Service,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ManagerStatus {
+    #[serde(default)]
+    pub manager_status: CrmStatus,
+    #[serde(default)]
+    pub lrm_status: BTreeMap<String, LrmStatus>, // Keyed by node name.
+    #[serde(default)]
+    pub quorum: Option<QuorumStatus>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct CrmStatus {
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_nodeid")]
+    pub master_node: Option<NodeId>,
+    #[serde(default)]
+    pub node_status: BTreeMap<String, String>, // Node name to state, e.g. "online".
+    #[serde(default)]
+    pub service_status: BTreeMap<String, ServiceStatus>, // Keyed by HA resource ID.
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ServiceStatus {
+    pub node: NodeId,
+    pub state: String,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_nodeid")]
+    pub target: Option<NodeId>, // Set while migrating or relocating.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub running: Option<bool>,
+    #[serde(default)]
+    pub uid: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LrmStatus {
+    #[serde(default)]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QuorumStatus {
+    pub node: NodeId,
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub quorate: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HaGroup, HaResourceId};
+    use crate::model::node::VMId;
+
+    #[test]
+    pub fn test_ha_ids() {
+        assert_eq!(
+            "ct:100".parse::<HaResourceId>().unwrap(),
+            HaResourceId::Container(VMId::from("100"))
+        );
+        assert_eq!("101".parse::<HaResourceId>().unwrap().to_string(), "vm:101");
+
+        let group: HaGroup = serde_json::from_str(
+            r#"{"group": "prefer-pve1", "nodes": "pve1:2,pve2:1,pve3", "restricted": 1, "type": "group"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(group.nodes.len(), 3);
+        assert_eq!(group.nodes[0].priority, Some(2));
+        assert_eq!(group.nodes[2].priority, None);
+        assert_eq!(group.restricted, Some(true));
+        assert_eq!(
+            serde_json::to_value(&group).unwrap()["nodes"],
+            "pve1:2,pve2:1,pve3"
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::node::{hardware::PciDevice, NodeId};
+
+/// A `vendor:device` id pair, e.g. `10de:1eb8`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub struct HardwareId {
+    pub vendor: u16,
+    pub device: u16,
+}
+
+impl HardwareId {
+    pub fn new(vendor: u16, device: u16) -> Self {
+        Self { vendor, device }
+    }
+}
+
+impl FromStr for HardwareId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (vendor, device) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid hardware id '{s}'"))?;
+
+        let parse = |value: &str| {
+            u16::from_str_radix(value.trim_start_matches("0x"), 16)
+                .map_err(|_| format!("Invalid hardware id '{s}'"))
+        };
+
+        Ok(Self {
+            vendor: parse(vendor)?,
+            device: parse(device)?,
+        })
+    }
+}
+
+impl TryFrom<String> for HardwareId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for HardwareId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vendor, self.device)
+    }
+}
+
+impl From<HardwareId> for String {
+    fn from(value: HardwareId) -> Self {
+        value.to_string()
+    }
+}
+
+/// A PCI device on one node, encoded by Proxmox as
+/// `node=<node>,path=<id>,id=<vendor:device>[,iommugroup=<n>][,subsystem-id=<vendor:device>][,description=<text>]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "String", into = "String")]
+pub struct PciMap {
+    pub node: NodeId,                     // The cluster node name.
+    pub path: String, // The path to the device. If the function is omitted, the whole device is mapped.
+    pub id: HardwareId, // The vendor and device ID that is expected.
+    pub iommugroup: Option<u32>, // The IOMMU group in which the device is to be expected in.
+    pub subsystem_id: Option<HardwareId>, // The subsystem vendor and device ID that is expected.
+    pub description: Option<String>, // Description of the node specific device.
+}
+
+impl PciMap {
+    /// Map a device as listed by the hardware enumeration of `node`.
+    pub fn from_device(node: NodeId, device: &PciDevice) -> Self {
+        Self {
+            node,
+            path: device.id.clone(),
+            id: HardwareId::new(device.vendor, device.device),
+            iommugroup: device.iommugroup,
+            subsystem_id: device
+                .subsystem_vendor
+                .zip(device.subsystem_device)
+                .map(|(vendor, device)| HardwareId::new(vendor, device)),
+            description: None,
+        }
+    }
+}
+
+impl FromStr for PciMap {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut node = None;
+        let mut path = None;
+        let mut id = None;
+        let mut iommugroup = None;
+        let mut subsystem_id = None;
+        let mut description = None;
+
+        for part in s.split(',').filter(|x| !x.is_empty()) {
+            match part.split_once('=') {
+                Some(("node", value)) => node = Some(NodeId(value.to_string())),
+                Some(("path", value)) => path = Some(value.to_string()),
+                Some(("id", value)) => id = Some(value.parse()?),
+                Some(("iommugroup", value)) => {
+                    iommugroup = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid IOMMU group '{value}'"))?,
+                    )
+                }
+                Some(("subsystem-id", value)) => subsystem_id = Some(value.parse()?),
+                Some(("description", value)) => description = Some(value.to_string()),
+                _ => return Err(format!("Invalid PCI mapping property '{part}'")),
+            }
+        }
+
+        Ok(PciMap {
+            node: node.ok_or_else(|| format!("Missing node in '{s}'"))?,
+            path: path.ok_or_else(|| format!("Missing path in '{s}'"))?,
+            id: id.ok_or_else(|| format!("Missing id in '{s}'"))?,
+            iommugroup,
+            subsystem_id,
+            description,
+        })
+    }
+}
+
+impl TryFrom<String> for PciMap {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for PciMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "node={},path={},id={}", self.node, self.path, self.id)?;
+        if let Some(iommugroup) = self.iommugroup {
+            write!(f, ",iommugroup={iommugroup}")?;
+        }
+        if let Some(subsystem_id) = self.subsystem_id {
+            write!(f, ",subsystem-id={subsystem_id}")?;
+        }
+        if let Some(description) = &self.description {
+            write!(f, ",description={description}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<PciMap> for String {
+    fn from(value: PciMap) -> Self {
+        value.to_string()
+    }
+}
+
+/// A USB device on one node, selected by `id` or by port `path`.
+/// Encoded by Proxmox as `node=<node>[,id=<vendor:device>][,path=<path>][,description=<text>]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "String", into = "String")]
+pub struct UsbMap {
+    pub node: NodeId,                // The cluster node name.
+    pub id: Option<HardwareId>,      // The vendor and device ID that is expected.
+    pub path: Option<String>,        // The path to the USB device.
+    pub description: Option<String>, // Description of the node specific device.
+}
+
+impl FromStr for UsbMap {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut node = None;
+        let mut id = None;
+        let mut path = None;
+        let mut description = None;
+
+        for part in s.split(',').filter(|x| !x.is_empty()) {
+            match part.split_once('=') {
+                Some(("node", value)) => node = Some(NodeId(value.to_string())),
+                Some(("id", value)) => id = Some(value.parse()?),
+                Some(("path", value)) => path = Some(value.to_string()),
+                Some(("description", value)) => description = Some(value.to_string()),
+                _ => return Err(format!("Invalid USB mapping property '{part}'")),
+            }
+        }
+
+        if id.is_none() && path.is_none() {
+            return Err(format!("Missing id or path in '{s}'"));
+        }
+
+        Ok(UsbMap {
+            node: node.ok_or_else(|| format!("Missing node in '{s}'"))?,
+            id,
+            path,
+            description,
+        })
+    }
+}
+
+impl TryFrom<String> for UsbMap {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for UsbMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "node={}", self.node)?;
+        if let Some(id) = self.id {
+            write!(f, ",id={id}")?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, ",path={path}")?;
+        }
+        if let Some(description) = &self.description {
+            write!(f, ",description={description}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<UsbMap> for String {
+    fn from(value: UsbMap) -> Self {
+        value.to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PciMapping {
+    pub id: String, // The ID of the logical PCI mapping.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub description: Option<String>, // Description of the logical PCI device.
+    pub map: Vec<PciMap>, // A list of maps for the cluster nodes.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub mdev: Option<bool>, // Marks the device(s) as being capable of providing mediated devices.
+    #[serde(default, skip_serializing)]
+    pub checks: Vec<MappingCheck>, // Only returned when listing with a node to check.
+}
+
+impl PciMapping {
+    pub fn new(id: impl Into<String>, map: Vec<PciMap>) -> Self {
+        Self {
+            id: id.into(),
+            description: None,
+            map,
+            mdev: None,
+            checks: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UsbMapping {
+    pub id: String, // The ID of the logical USB mapping.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub description: Option<String>, // Description of the logical USB device.
+    pub map: Vec<UsbMap>, // A list of maps for the cluster nodes.
+    #[serde(default, alias = "errors", skip_serializing)]
+    pub checks: Vec<MappingCheck>, // Only returned when listing with a node to check.
+}
+
+impl UsbMapping {
+    pub fn new(id: impl Into<String>, map: Vec<UsbMap>) -> Self {
+        Self {
+            id: id.into(),
+            description: None,
+            map,
+            checks: Vec::new(),
+        }
+    }
+}
+
+/// PCI mapping settings that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PciMappingField {
+    Description,
+    Map,
+    Mdev,
+}
+
+impl Display for PciMappingField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Description => write!(f, "description"),
+            Self::Map => write!(f, "map"),
+            Self::Mdev => write!(f, "mdev"),
+        }
+    }
+}
+
+/// USB mapping settings that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsbMappingField {
+    Description,
+    Map,
+}
+
+impl Display for UsbMappingField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Description => write!(f, "description"),
+            Self::Map => write!(f, "map"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MappingCheck {
+    pub severity: MappingCheckSeverity,
+    pub message: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MappingCheckSeverity {
+    Warning,
+    Error,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HardwareId, PciMap, UsbMap};
+
+    #[test]
+    pub fn test_mapping_entries() {
+        let map: PciMap =
+            "node=pve1,path=0000:01:00.0,id=10de:1eb8,iommugroup=14,subsystem-id=10de:12a2"
+                .parse()
+                .unwrap();
+
+        assert_eq!(map.node.to_string(), "pve1");
+        assert_eq!(map.path, "0000:01:00.0");
+        assert_eq!(map.id, HardwareId::new(0x10de, 0x1eb8));
+        assert_eq!(map.iommugroup, Some(14));
+        assert_eq!(
+            map.to_string(),
+            "node=pve1,path=0000:01:00.0,id=10de:1eb8,iommugroup=14,subsystem-id=10de:12a2"
+        );
+
+        assert!("node=pve1,id=10de:1eb8".parse::<PciMap>().is_err());
+
+        let map: UsbMap = "node=pve2,path=1-2".parse().unwrap();
+        assert_eq!(map.id, None);
+        assert_eq!(map.to_string(), "node=pve2,path=1-2");
+
+        assert!("node=pve2".parse::<UsbMap>().is_err());
+    }
+}
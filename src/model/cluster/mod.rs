@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-mod notifications;
+pub mod acme;
+pub mod backup;
+pub mod ha;
+pub mod mapping;
 pub mod resources;
 pub mod sdn;
 pub mod status;
@@ -1,3 +0,0 @@
-pub mod endpoints;
-pub mod matchers;
-pub mod targets;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Alias {
+    pub name: String, // Alias name.
+    pub cidr: String, // Network/IP specification in CIDR format.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comment: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    #[serde(default)]
+    pub ipversion: Option<u8>,
+}
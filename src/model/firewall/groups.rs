@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SecurityGroup {
+    pub group: String, // Security Group name.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comment: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Macro {
+    #[serde(rename = "macro")]
+    pub name: String, // Macro name.
+    #[serde(rename = "descr")]
+    pub description: String, // More verbose description (if available).
+}
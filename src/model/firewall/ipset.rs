@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct IPSet {
+    pub name: String, // IP set name.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comment: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct IPSetEntry {
+    pub cidr: String, // Network/IP specification in CIDR format, or an alias name.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comment: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub nomatch: bool, // Exclude this entry from the set.
+}
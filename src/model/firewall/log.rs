@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+pub use crate::model::node::logs::LogEntry;
+
+#[derive(Serialize, Debug, Default)]
+pub struct LogFilter {
+    pub limit: Option<u64>, // Only list this amount of lines.
+    pub start: Option<u64>, // List lines beginning from this offset.
+    pub since: Option<u64>, // Display log since this UNIX epoch.
+    pub until: Option<u64>, // Display log until this UNIX epoch.
+}
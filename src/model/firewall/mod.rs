@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+pub mod aliases;
+pub mod groups;
+pub mod ipset;
+pub mod log;
+pub mod options;
+pub mod refs;
+pub mod rules;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Policy {
+    Accept,
+    Drop,
+    Reject,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Emerg,
+    Alert,
+    Crit,
+    Err,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+    Nolog,
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Emerg => write!(f, "emerg"),
+            Self::Alert => write!(f, "alert"),
+            Self::Crit => write!(f, "crit"),
+            Self::Err => write!(f, "err"),
+            Self::Warning => write!(f, "warning"),
+            Self::Notice => write!(f, "notice"),
+            Self::Info => write!(f, "info"),
+            Self::Debug => write!(f, "debug"),
+            Self::Nolog => write!(f, "nolog"),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::{LogLevel, Policy};
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct GuestOptions {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub enable: Option<bool>, // Enable/disable firewall rules.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub dhcp: Option<bool>, // Enable DHCP.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub ipfilter: Option<bool>, // Enable default IP filters. This is equivalent to adding an empty ipfilter-net<id> ipset for every interface.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub macfilter: Option<bool>, // Enable/disable MAC address filter.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub ndp: Option<bool>, // Enable NDP (Neighbor Discovery Protocol).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub radv: Option<bool>, // Allow sending Router Advertisement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level_in: Option<LogLevel>, // Log level for incoming traffic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level_out: Option<LogLevel>, // Log level for outgoing traffic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_in: Option<Policy>, // Input policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_out: Option<Policy>, // Output policy.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+}
+
+/// Guest firewall options that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuestOptionsField {
+    Enable,
+    Dhcp,
+    IpFilter,
+    MacFilter,
+    Ndp,
+    Radv,
+    LogLevelIn,
+    LogLevelOut,
+    PolicyIn,
+    PolicyOut,
+}
+
+impl Display for GuestOptionsField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Enable => write!(f, "enable"),
+            Self::Dhcp => write!(f, "dhcp"),
+            Self::IpFilter => write!(f, "ipfilter"),
+            Self::MacFilter => write!(f, "macfilter"),
+            Self::Ndp => write!(f, "ndp"),
+            Self::Radv => write!(f, "radv"),
+            Self::LogLevelIn => write!(f, "log_level_in"),
+            Self::LogLevelOut => write!(f, "log_level_out"),
+            Self::PolicyIn => write!(f, "policy_in"),
+            Self::PolicyOut => write!(f, "policy_out"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ClusterOptions {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub enable: Option<bool>, // Enable or disable the firewall cluster wide.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub ebtables: Option<bool>, // Enable ebtables rules cluster wide.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_ratelimit: Option<LogRateLimit>, // Log ratelimiting settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_in: Option<Policy>, // Input policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_out: Option<Policy>, // Output policy.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+}
+
+/// Cluster firewall options that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterOptionsField {
+    Enable,
+    Ebtables,
+    LogRateLimit,
+    PolicyIn,
+    PolicyOut,
+}
+
+impl Display for ClusterOptionsField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Enable => write!(f, "enable"),
+            Self::Ebtables => write!(f, "ebtables"),
+            Self::LogRateLimit => write!(f, "log_ratelimit"),
+            Self::PolicyIn => write!(f, "policy_in"),
+            Self::PolicyOut => write!(f, "policy_out"),
+        }
+    }
+}
+
+/// Encoded by Proxmox as `enable=1,burst=5,rate=1/second`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "String", into = "String")]
+pub struct LogRateLimit {
+    pub enable: bool,         // Enable or disable log rate limiting.
+    pub burst: Option<u64>, // Initial burst of packages which will always get logged before the rate is applied.
+    pub rate: Option<String>, // Frequency with which the burst bucket gets refilled, e.g. '1/second'.
+}
+
+impl FromStr for LogRateLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut limit = LogRateLimit {
+            enable: true,
+            burst: None,
+            rate: None,
+        };
+
+        for part in s.split(',').filter(|x| !x.is_empty()) {
+            match part.split_once('=') {
+                Some(("enable", value)) => limit.enable = value != "0",
+                Some(("burst", value)) => {
+                    limit.burst = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid burst '{value}'"))?,
+                    )
+                }
+                Some(("rate", value)) => limit.rate = Some(value.to_string()),
+                // The first value may omit its key
+                None => limit.enable = part != "0",
+                Some((key, _)) => return Err(format!("Unknown log_ratelimit property '{key}'")),
+            }
+        }
+
+        Ok(limit)
+    }
+}
+
+impl TryFrom<String> for LogRateLimit {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<LogRateLimit> for String {
+    fn from(value: LogRateLimit) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for LogRateLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "enable={}", u8::from(self.enable))?;
+        if let Some(burst) = self.burst {
+            write!(f, ",burst={burst}")?;
+        }
+        if let Some(rate) = &self.rate {
+            write!(f, ",rate={rate}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogRateLimit;
+
+    #[test]
+    pub fn test_log_ratelimit() {
+        let limit: LogRateLimit = "enable=1,burst=5,rate=1/second".parse().unwrap();
+
+        assert!(limit.enable);
+        assert_eq!(limit.burst, Some(5));
+        assert_eq!(limit.rate.as_deref(), Some("1/second"));
+        assert_eq!(limit.to_string(), "enable=1,burst=5,rate=1/second");
+
+        assert!(!"0".parse::<LogRateLimit>().unwrap().enable);
+        assert!("enable=1,foo=bar".parse::<LogRateLimit>().is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RefType {
+    Alias,
+    IPSet,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FirewallRef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ref_type: RefType,
+    pub r#ref: String, // Reference to use in rules, e.g. '+name' for IP sets.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comment: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub scope: Option<String>, // Where the alias/IP set is defined, 'dc' or 'guest'.
+}
@@ -0,0 +1,235 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::LogLevel;
+
+/// A firewall rule. Used for guest, cluster and security group rules alike.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Rule {
+    #[serde(rename = "type")]
+    pub direction: Direction, // Rule type.
+    pub action: Action, // Rule action ('ACCEPT', 'DROP', 'REJECT') or security group name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos: Option<u64>, // Rule position. When creating a rule, the new rule is inserted at this position.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::option_int_bool"
+    )]
+    pub enable: Option<bool>, // Flag to enable/disable a rule.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub comment: Option<String>, // Descriptive comment.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "macro",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub macro_name: Option<String>, // Use predefined standard macro.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub source: Option<String>, // Restrict packet source address. Can be a single IP, a CIDR, an alias or an IPSet (+name).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub dest: Option<String>, // Restrict packet destination address. Can be a single IP, a CIDR, an alias or an IPSet (+name).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub proto: Option<String>, // IP protocol. You can use protocol names ('tcp'/'udp') or simple numbers, as defined in '/etc/protocols'.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub dport: Option<String>, // Restrict TCP/UDP destination port. Port ranges can be specified with '\d+:\d+', for example '80:85'.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub sport: Option<String>, // Restrict TCP/UDP source port. Port ranges can be specified with '\d+:\d+', for example '80:85'.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub iface: Option<String>, // Network interface name. You have to use network configuration key names for VMs and containers ('net\d+').
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "icmp-type",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub icmp_type: Option<String>, // Specify icmp-type. Only valid if proto equals 'icmp' or 'icmpv6'/'ipv6-icmp'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<LogLevel>, // Log level for firewall rule.
+    #[serde(default, skip_serializing)]
+    pub ipversion: Option<u8>, // IP version of the rule, derived from source and destination.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub digest: Option<String>, // Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+}
+
+impl Rule {
+    pub fn new(direction: Direction, action: impl Into<Action>) -> Self {
+        Self {
+            direction,
+            action: action.into(),
+            pos: None,
+            enable: None,
+            comment: None,
+            macro_name: None,
+            source: None,
+            dest: None,
+            proto: None,
+            dport: None,
+            sport: None,
+            iface: None,
+            icmp_type: None,
+            log: None,
+            ipversion: None,
+            digest: None,
+        }
+    }
+
+    /// Rule referencing a security group. Security groups are always of type `group`.
+    pub fn group(name: impl Into<String>) -> Self {
+        Self::new(Direction::Group, Action::Group(name.into()))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    In,
+    Out,
+    Forward,
+    Group,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "String", into = "String")]
+pub enum Action {
+    Accept,
+    Drop,
+    Reject,
+    Group(String), // Name of the security group, for rules of type 'group'.
+}
+
+impl From<String> for Action {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ACCEPT" => Self::Accept,
+            "DROP" => Self::Drop,
+            "REJECT" => Self::Reject,
+            _ => Self::Group(value),
+        }
+    }
+}
+
+impl From<&str> for Action {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+impl From<Action> for String {
+    fn from(value: Action) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Accept => write!(f, "ACCEPT"),
+            Self::Drop => write!(f, "DROP"),
+            Self::Reject => write!(f, "REJECT"),
+            Self::Group(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Rule properties that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RuleField {
+    Comment,
+    Macro,
+    Source,
+    Dest,
+    Proto,
+    DPort,
+    SPort,
+    IFace,
+    IcmpType,
+    Log,
+}
+
+impl Display for RuleField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Comment => write!(f, "comment"),
+            Self::Macro => write!(f, "macro"),
+            Self::Source => write!(f, "source"),
+            Self::Dest => write!(f, "dest"),
+            Self::Proto => write!(f, "proto"),
+            Self::DPort => write!(f, "dport"),
+            Self::SPort => write!(f, "sport"),
+            Self::IFace => write!(f, "iface"),
+            Self::IcmpType => write!(f, "icmp-type"),
+            Self::Log => write!(f, "log"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Direction, Rule};
+
+    #[test]
+    pub fn test_rule_roundtrip() {
+        let rule: Rule = serde_json::from_str(
+            r#"{"pos":0,"type":"in","action":"ACCEPT","enable":1,"proto":"tcp","dport":"22","log":"nolog","ipversion":4,"digest":""}"#,
+        )
+        .unwrap();
+
+        assert_eq!(rule.direction, Direction::In);
+        assert_eq!(rule.action, Action::Accept);
+        assert_eq!(rule.enable, Some(true));
+        assert_eq!(rule.digest, None);
+
+        let json = serde_json::to_value(&rule).unwrap();
+        assert_eq!(json["action"], "ACCEPT");
+        assert_eq!(json["dport"], "22");
+        assert!(json.get("ipversion").is_none());
+    }
+
+    #[test]
+    pub fn test_group_rule() {
+        let rule = Rule::group("webservers");
+
+        let json = serde_json::to_value(&rule).unwrap();
+        assert_eq!(json["type"], "group");
+        assert_eq!(json["action"], "webservers");
+        assert_eq!(
+            serde_json::from_value::<Rule>(json).unwrap().action,
+            Action::Group("webservers".into())
+        );
+    }
+}
@@ -5,6 +5,7 @@ use tracing::error;
 use crate::error::ProxmoxAPIError;
 
 pub mod cluster;
+pub mod firewall;
 pub mod node;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -94,14 +95,14 @@ pub enum Size {
     B(u64),
 }
 
-impl ToString for Size {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Size::TB(q) => format!("{q}T"),
-            Size::GB(q) => format!("{q}G"),
-            Size::MB(q) => format!("{q}M"),
-            Size::KB(q) => format!("{q}K"),
-            Size::B(q) => format!("{q}"),
+            Size::TB(q) => write!(f, "{q}T"),
+            Size::GB(q) => write!(f, "{q}G"),
+            Size::MB(q) => write!(f, "{q}M"),
+            Size::KB(q) => write!(f, "{q}K"),
+            Size::B(q) => write!(f, "{q}"),
         }
     }
 }
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Package {
+    pub package: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>, // Candidate version for upgradable packages, installed version otherwise.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub old_version: Option<String>, // Currently installed version.
+    #[serde(default)]
+    pub arch: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub origin: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub section: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub current_state: Option<String>, // Only for versions, e.g. 'Installed' or 'NotInstalled'.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub running_kernel: Option<String>, // Only for the kernel package.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub manager_version: Option<String>, // Only for pve-manager, the version of the running API server.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RepositoryConfiguration {
+    pub digest: String, // Common digest of all files.
+    #[serde(default)]
+    pub errors: Vec<RepositoryFileError>, // List of problematic repository files.
+    #[serde(default)]
+    pub files: Vec<RepositoryFile>, // List of parsed repository files.
+    #[serde(default)]
+    pub infos: Vec<RepositoryInfo>, // Additional information/warnings for APT repositories.
+    #[serde(default, rename = "standard-repos")]
+    pub standard_repos: Vec<StandardRepository>, // List of standard repositories and their configuration status.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RepositoryFileError {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RepositoryFile {
+    pub path: String,
+    #[serde(rename = "file-type")]
+    pub file_type: String, // 'list' or 'sources'.
+    #[serde(default)]
+    pub digest: Vec<u8>,
+    #[serde(default)]
+    pub repositories: Vec<Repository>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Repository {
+    #[serde(default)]
+    pub types: Vec<String>, // 'deb' and/or 'deb-src'.
+    #[serde(default, rename = "URIs")]
+    pub uris: Vec<String>,
+    #[serde(default)]
+    pub suites: Vec<String>,
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(deserialize_with = "crate::deserializers::int_bool")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub file_type: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RepositoryInfo {
+    pub path: String,
+    pub index: u64, // Index of the repository within the file.
+    pub kind: String,
+    pub message: String,
+    #[serde(default)]
+    pub property: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StandardRepository {
+    pub handle: StandardRepositoryHandle,
+    pub name: String,
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub status: Option<bool>, // Configured and enabled, configured and disabled or not configured at all.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum StandardRepositoryHandle {
+    Enterprise,
+    NoSubscription,
+    Test,
+    CephQuincyEnterprise,
+    CephQuincyNoSubscription,
+    CephQuincyTest,
+    CephReefEnterprise,
+    CephReefNoSubscription,
+    CephReefTest,
+    CephSquidEnterprise,
+    CephSquidNoSubscription,
+    CephSquidTest,
+    #[serde(other)]
+    Other,
+}
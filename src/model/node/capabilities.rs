@@ -0,0 +1,144 @@
+use serde::Deserialize;
+
+use super::status::CpuInfo;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CpuModel {
+    pub name: String, // Name of the CPU model. Identifies it for subsequent API calls. Prefixed with 'custom-' for custom models.
+    pub vendor: String, // CPU vendor visible to the guest when this model is selected. Vendor of 'reported-model' in case of custom models.
+    #[serde(deserialize_with = "crate::deserializers::int_bool")]
+    pub custom: bool, // True if this is a custom CPU model.
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MachineType {
+    pub id: String, // Full name of machine type and version.
+    #[serde(rename = "type")]
+    pub machine_type: MachineKind, // The machine type.
+    pub version: String, // The machine version.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub changes: Option<String>, // Notes about changes for that machine version.
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MachineKind {
+    I440fx,
+    Q35,
+    Virt,
+    #[serde(other)]
+    Unknown,
+}
+
+// Generic models from most to least capable, with the host CPU flags each one requires
+const GENERIC_CPU_MODELS: &[(&str, &[&str])] = &[
+    (
+        "x86-64-v4",
+        &[
+            "avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl", "avx", "avx2", "bmi1",
+            "bmi2", "f16c", "fma", "abm", "movbe", "xsave", "aes", "cx16", "lahf_lm", "popcnt",
+            "pni", "sse4_1", "sse4_2", "ssse3",
+        ],
+    ),
+    (
+        "x86-64-v3",
+        &[
+            "avx", "avx2", "bmi1", "bmi2", "f16c", "fma", "abm", "movbe", "xsave", "aes", "cx16",
+            "lahf_lm", "popcnt", "pni", "sse4_1", "sse4_2", "ssse3",
+        ],
+    ),
+    (
+        "x86-64-v2-AES",
+        &[
+            "aes", "cx16", "lahf_lm", "popcnt", "pni", "sse4_1", "sse4_2", "ssse3",
+        ],
+    ),
+    (
+        "x86-64-v2",
+        &[
+            "cx16", "lahf_lm", "popcnt", "pni", "sse4_1", "sse4_2", "ssse3",
+        ],
+    ),
+    ("kvm64", &[]),
+    ("qemu64", &[]),
+];
+
+/// The most capable generic CPU model every node can run, so guests can live migrate between them.
+///
+/// Each node is given with the CPU models its QEMU supports and its host CPU information.
+/// Returns `None` without any nodes.
+pub fn lowest_common_cpu_model(nodes: &[(&[CpuModel], &CpuInfo)]) -> Option<&'static str> {
+    if nodes.is_empty() {
+        return None;
+    }
+
+    GENERIC_CPU_MODELS
+        .iter()
+        .find(|(name, flags)| {
+            nodes.iter().all(|(models, cpuinfo)| {
+                models.iter().any(|model| model.name == *name)
+                    && flags.iter().all(|flag| cpuinfo.has_flag(flag))
+            })
+        })
+        .map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lowest_common_cpu_model, CpuModel, MachineKind, MachineType};
+    use crate::model::node::status::CpuInfo;
+
+    #[test]
+    pub fn test_lowest_common_cpu_model() {
+        let models: Vec<CpuModel> = serde_json::from_str(
+            r#"[
+                {"name": "x86-64-v2-AES", "vendor": "default", "custom": 0},
+                {"name": "x86-64-v3", "vendor": "default", "custom": 0},
+                {"name": "x86-64-v4", "vendor": "default", "custom": 0},
+                {"name": "kvm64", "vendor": "default", "custom": 0}
+            ]"#,
+        )
+        .unwrap();
+
+        let cpuinfo = |flags: &str| -> CpuInfo {
+            serde_json::from_value(serde_json::json!({
+                "model": "test", "cpus": 4, "cores": 4, "sockets": 1, "mhz": "2000", "flags": flags
+            }))
+            .unwrap()
+        };
+
+        let modern = cpuinfo("avx avx2 bmi1 bmi2 f16c fma abm movbe xsave aes cx16 lahf_lm popcnt pni sse4_1 sse4_2 ssse3");
+        let older = cpuinfo("aes cx16 lahf_lm popcnt pni sse4_1 sse4_2 ssse3");
+        let ancient = cpuinfo("cx16 pni");
+
+        assert_eq!(
+            lowest_common_cpu_model(&[(&models, &modern)]),
+            Some("x86-64-v3")
+        );
+        assert_eq!(
+            lowest_common_cpu_model(&[(&models, &modern), (&models, &older)]),
+            Some("x86-64-v2-AES")
+        );
+        assert_eq!(
+            lowest_common_cpu_model(&[(&models, &modern), (&models, &ancient)]),
+            Some("kvm64")
+        );
+        assert_eq!(lowest_common_cpu_model(&[]), None);
+    }
+
+    #[test]
+    pub fn test_machine_types() {
+        let machines: Vec<MachineType> = serde_json::from_str(
+            r#"[
+                {"id": "pc-q35-8.1", "type": "q35", "version": "8.1"},
+                {"id": "pc-i440fx-8.1", "type": "i440fx", "version": "8.1", "changes": ""},
+                {"id": "microvm", "type": "microvm", "version": "8.1"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(machines[0].machine_type, MachineKind::Q35);
+        assert_eq!(machines[1].changes, None);
+        assert_eq!(machines[2].machine_type, MachineKind::Unknown);
+    }
+}
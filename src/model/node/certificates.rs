@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CertificateInfo {
+    pub filename: String,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub fingerprint: Option<String>, // Certificate SHA 256 fingerprint.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub issuer: Option<String>, // Certificate issuer name.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub subject: Option<String>, // Certificate subject name.
+    #[serde(default)]
+    pub san: Vec<String>, // List of Certificate's SubjectAlternativeName entries.
+    #[serde(
+        default,
+        rename = "notbefore",
+        with = "chrono::serde::ts_seconds_option"
+    )]
+    pub not_before: Option<DateTime<Utc>>, // Certificate's notBefore timestamp.
+    #[serde(
+        default,
+        rename = "notafter",
+        with = "chrono::serde::ts_seconds_option"
+    )]
+    pub not_after: Option<DateTime<Utc>>, // Certificate's notAfter timestamp.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub pem: Option<String>, // Certificate in PEM format.
+    #[serde(default, rename = "public-key-bits")]
+    pub public_key_bits: Option<u32>, // Certificate's public key size.
+    #[serde(
+        default,
+        rename = "public-key-type",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub public_key_type: Option<String>, // Certificate's public key algorithm.
+}
+
+impl CertificateInfo {
+    /// Time left until the certificate expires, negative if it already did.
+    pub fn expires_in(&self) -> Option<chrono::Duration> {
+        self.not_after.map(|not_after| not_after - Utc::now())
+    }
+
+    /// Whether the certificate expires within the given time, or already has.
+    pub fn expires_within(&self, duration: chrono::Duration) -> bool {
+        self.expires_in()
+            .map(|left| left <= duration)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CertificateInfo;
+
+    #[test]
+    pub fn test_certificate_info() {
+        let certificates: Vec<CertificateInfo> = serde_json::from_str(
+            r#"[{"filename":"pve-root-ca.pem","fingerprint":"7C:1F:0A:3B","issuer":"/CN=Proxmox Virtual Environment/OU=2c9e/O=PVE Cluster Manager CA","subject":"/CN=Proxmox Virtual Environment/OU=2c9e/O=PVE Cluster Manager CA","san":[],"notbefore":1609459200,"notafter":4102444800,"pem":"-----BEGIN CERTIFICATE-----","public-key-bits":4096,"public-key-type":"rsaEncryption"},{"filename":"pve-ssl.pem","fingerprint":"","issuer":"/CN=Proxmox Virtual Environment","subject":"/OU=PVE Cluster Node/O=Proxmox Virtual Environment/CN=pve1.example.com","san":["127.0.0.1","0000:0000:0000:0000:0000:0000:0000:0001","localhost","pve1","pve1.example.com"],"notbefore":1609459200,"notafter":1640995200,"public-key-type":"rsaEncryption"}]"#,
+        )
+        .unwrap();
+
+        let root = &certificates[0];
+        assert_eq!(root.public_key_bits, Some(4096));
+        assert!(!root.expires_within(chrono::Duration::days(30)));
+
+        let node = &certificates[1];
+        assert_eq!(node.fingerprint, None);
+        assert_eq!(node.san.len(), 5);
+        assert_eq!(node.pem, None);
+        assert!(node.expires_within(chrono::Duration::days(30)));
+    }
+}
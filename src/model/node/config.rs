@@ -1,6 +1,9 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum Property {
     ACME,
     ACMEDomain0,
@@ -10,36 +13,313 @@ pub enum Property {
     ACMEDomain4,
     ACMEDomain5,
     Description,
+    #[serde(rename = "startall-onboot-delay")]
     StartAllOnBootDelay,
     WakeOnLan,
 }
 
-#[derive(Deserialize, Debug)]
+impl Display for Property {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ACME => write!(f, "acme"),
+            Self::ACMEDomain0 => write!(f, "acmedomain0"),
+            Self::ACMEDomain1 => write!(f, "acmedomain1"),
+            Self::ACMEDomain2 => write!(f, "acmedomain2"),
+            Self::ACMEDomain3 => write!(f, "acmedomain3"),
+            Self::ACMEDomain4 => write!(f, "acmedomain4"),
+            Self::ACMEDomain5 => write!(f, "acmedomain5"),
+            Self::Description => write!(f, "description"),
+            Self::StartAllOnBootDelay => write!(f, "startall-onboot-delay"),
+            Self::WakeOnLan => write!(f, "wakeonlan"),
+        }
+    }
+}
+
+/// Node configuration. Unset fields are left untouched when passed to `PveNode::update_config`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct NodeConfiguration {
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acme: Option<String>, // Node specific ACME settings.
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acmedomain0: Option<String>, // ACME domain and validation plugin
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acmedomain1: Option<String>, // ACME domain and validation plugin
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acmedomain2: Option<String>, // ACME domain and validation plugin
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acmedomain3: Option<String>, // ACME domain and validation plugin
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acmedomain4: Option<String>, // ACME domain and validation plugin
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
-    pub acmedomain5: Option<String>, // ACME domain and validation plugin
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acme: Option<AcmeSettings>, // Node specific ACME settings.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acmedomain0: Option<AcmeDomain>, // ACME domain and validation plugin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acmedomain1: Option<AcmeDomain>, // ACME domain and validation plugin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acmedomain2: Option<AcmeDomain>, // ACME domain and validation plugin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acmedomain3: Option<AcmeDomain>, // ACME domain and validation plugin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acmedomain4: Option<AcmeDomain>, // ACME domain and validation plugin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_parsed"
+    )]
+    pub acmedomain5: Option<AcmeDomain>, // ACME domain and validation plugin
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
     pub description: Option<String>, // Description for the Node. Shown in the web-interface node notes panel. This is saved as comment inside the configuration file.
-    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
     pub digest: Option<String>, // Prevent changes if current configuration file has different SHA1 digest. This can be used to prevent concurrent modifications.
-    #[serde(default, rename = "startall-onboot-delay")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "startall-onboot-delay"
+    )]
     pub start_all_on_boot_delay: Option<u64>, // Initial delay in seconds, before starting all the Virtual Guests with on-boot enabled.
     #[serde(
         default,
+        skip_serializing_if = "Option::is_none",
         rename = "wakeonlan",
         deserialize_with = "crate::deserializers::non_empty_str"
     )]
     pub wake_on_lan: Option<String>, // MAC address for wake on LAN
 }
+
+impl NodeConfiguration {
+    /// All configured ACME domains, `acmedomain0` to `acmedomain5`.
+    pub fn acme_domains(&self) -> Vec<&AcmeDomain> {
+        [
+            &self.acmedomain0,
+            &self.acmedomain1,
+            &self.acmedomain2,
+            &self.acmedomain3,
+            &self.acmedomain4,
+            &self.acmedomain5,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Encoded by Proxmox as `account=<name>,domains=<domain;domain;...>`.
+#[derive(Serialize, Debug, Default, Clone, PartialEq)]
+#[serde(into = "String")]
+pub struct AcmeSettings {
+    pub account: Option<String>,         // ACME account config file name.
+    pub domains: Vec<String>, // List of domains for this node's ACME certificate, validated with the standalone plugin.
+    pub other: BTreeMap<String, String>, // Options unknown to this crate, kept so they are written back unchanged.
+}
+
+impl FromStr for AcmeSettings {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut settings = AcmeSettings::default();
+
+        for part in s.split(',').filter(|x| !x.is_empty()) {
+            match part.split_once('=') {
+                Some(("account", value)) => settings.account = Some(value.to_string()),
+                Some(("domains", value)) => {
+                    settings.domains = value
+                        .split(';')
+                        .filter(|x| !x.is_empty())
+                        .map(String::from)
+                        .collect()
+                }
+                Some((key, value)) => {
+                    settings.other.insert(key.to_string(), value.to_string());
+                }
+                None => return Err(format!("Invalid ACME setting '{part}'")),
+            }
+        }
+
+        Ok(settings)
+    }
+}
+
+impl Display for AcmeSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(account) = &self.account {
+            parts.push(format!("account={account}"));
+        }
+        if !self.domains.is_empty() {
+            parts.push(format!("domains={}", self.domains.join(";")));
+        }
+        for (key, value) in &self.other {
+            parts.push(format!("{key}={value}"));
+        }
+        f.write_str(&parts.join(","))
+    }
+}
+
+impl From<AcmeSettings> for String {
+    fn from(value: AcmeSettings) -> Self {
+        value.to_string()
+    }
+}
+
+/// Encoded by Proxmox as `[domain=]<domain>,alias=<domain>,plugin=<name>`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(into = "String")]
+pub struct AcmeDomain {
+    pub domain: String,                  // Domain for this node's ACME certificate.
+    pub alias: Option<String>,           // Alias for the Domain to verify ACME Challenge over DNS.
+    pub plugin: Option<String>,          // The ACME plugin ID. Defaults to 'standalone'.
+    pub other: BTreeMap<String, String>, // Properties unknown to this crate, kept so they are written back unchanged.
+}
+
+impl AcmeDomain {
+    pub fn new(domain: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+            alias: None,
+            plugin: None,
+            other: BTreeMap::new(),
+        }
+    }
+}
+
+impl FromStr for AcmeDomain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut domain = None;
+        let mut alias = None;
+        let mut plugin = None;
+        let mut other = BTreeMap::new();
+
+        for part in s.split(',').filter(|x| !x.is_empty()) {
+            match part.split_once('=') {
+                Some(("domain", value)) => domain = Some(value.to_string()),
+                Some(("alias", value)) => alias = Some(value.to_string()),
+                Some(("plugin", value)) => plugin = Some(value.to_string()),
+                // The domain is the default key and may be given without it
+                None => domain = Some(part.to_string()),
+                Some((key, value)) => {
+                    other.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+
+        Ok(AcmeDomain {
+            domain: domain.ok_or_else(|| format!("Missing domain in '{s}'"))?,
+            alias,
+            plugin,
+            other,
+        })
+    }
+}
+
+impl Display for AcmeDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "domain={}", self.domain)?;
+        if let Some(alias) = &self.alias {
+            write!(f, ",alias={alias}")?;
+        }
+        if let Some(plugin) = &self.plugin {
+            write!(f, ",plugin={plugin}")?;
+        }
+        for (key, value) in &self.other {
+            write!(f, ",{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<AcmeDomain> for String {
+    fn from(value: AcmeDomain) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AcmeDomain, AcmeSettings, NodeConfiguration};
+
+    #[test]
+    pub fn test_acme_domain() {
+        let domain: AcmeDomain = "pve1.example.com,plugin=cloudflare,alias=_acme.example.org"
+            .parse()
+            .unwrap();
+
+        assert_eq!(domain.domain, "pve1.example.com");
+        assert_eq!(domain.plugin.as_deref(), Some("cloudflare"));
+        assert_eq!(domain.alias.as_deref(), Some("_acme.example.org"));
+        assert_eq!(
+            domain.to_string(),
+            "domain=pve1.example.com,alias=_acme.example.org,plugin=cloudflare"
+        );
+
+        assert!("plugin=cloudflare".parse::<AcmeDomain>().is_err());
+
+        let domain: AcmeDomain = "pve1.example.com,validation=dns".parse().unwrap();
+        assert_eq!(domain.to_string(), "domain=pve1.example.com,validation=dns");
+    }
+
+    #[test]
+    pub fn test_acme_settings() {
+        let settings: AcmeSettings = "account=default,domains=a.example.com;b.example.com"
+            .parse()
+            .unwrap();
+
+        assert_eq!(settings.account.as_deref(), Some("default"));
+        assert_eq!(settings.domains, vec!["a.example.com", "b.example.com"]);
+        assert_eq!(
+            settings.to_string(),
+            "account=default,domains=a.example.com;b.example.com"
+        );
+
+        // Options added by newer Proxmox versions are kept
+        let settings: AcmeSettings = "account=default,renew-days=20".parse().unwrap();
+        assert_eq!(
+            settings.other.get("renew-days").map(String::as_str),
+            Some("20")
+        );
+        assert_eq!(settings.to_string(), "account=default,renew-days=20");
+    }
+
+    #[test]
+    pub fn test_node_configuration() {
+        let config: NodeConfiguration = serde_json::from_str(
+            r#"{"acme":"account=default","acmedomain0":"pve1.example.com","acmedomain1":"","digest":"abc"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.acme_domains().len(), 1);
+        assert_eq!(config.acme.unwrap().account.as_deref(), Some("default"));
+
+        let json = serde_json::to_value(&NodeConfiguration {
+            acmedomain2: Some(AcmeDomain::new("pve2.example.com")),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"acmedomain2": "domain=pve2.example.com"})
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TermCommand {
+    CephInstall,
+    Login,
+    Upgrade,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TermProxy {
+    #[serde(deserialize_with = "crate::deserializers::number_or_str")]
+    pub port: u16, // Port of the spawned termproxy, needed to open the vncwebsocket.
+    pub ticket: String, // Ticket used to authenticate against the termproxy.
+    pub upid: String,   // Task that keeps the termproxy alive.
+    pub user: String,   // User the ticket was issued for.
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct VncProxy {
+    #[serde(deserialize_with = "crate::deserializers::number_or_str")]
+    pub port: u16, // Port of the spawned vncproxy.
+    pub ticket: String, // Ticket used to authenticate against the vncproxy.
+    pub upid: String,   // Task that keeps the vncproxy alive.
+    pub user: String,   // User the ticket was issued for.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub cert: Option<String>, // Certificate of the node, for non-websocket clients.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub password: Option<String>, // Generated VNC password, only when requested.
+}
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskTypeFilter {
+    Unused,
+    JournalDisks,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiskType {
+    Hdd,
+    Ssd,
+    Nvme,
+    Usb,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Disk {
+    pub devpath: String, // The device path.
+    #[serde(rename = "type")]
+    pub disk_type: DiskType,
+    #[serde(default)]
+    pub size: u64, // Size in bytes.
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub gpt: bool, // Disk has a GPT partition table.
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub mounted: bool,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub used: Option<String>, // What the disk is used for, e.g. 'LVM', 'ZFS', 'partitions'.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub health: Option<String>, // SMART health, e.g. 'PASSED' or 'OK'.
+    #[serde(default, deserialize_with = "crate::deserializers::lenient_number")]
+    pub wearout: Option<u8>, // Remaining SSD life in percent, if reported.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub model: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub serial: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub vendor: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub wwn: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::lenient_number")]
+    pub rpm: Option<u32>, // Rotation speed, 0 for SSDs.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub by_id_link: Option<String>, // Stable /dev/disk/by-id path.
+    #[serde(default)]
+    pub osdid: Option<i64>, // Ceph OSD using the disk, -1 if none.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub parent: Option<String>, // Parent disk, for partitions.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SmartData {
+    pub health: String,
+    #[serde(rename = "type", default)]
+    pub smart_type: Option<SmartType>,
+    #[serde(default)]
+    pub attributes: Vec<SmartAttribute>, // Only for ATA disks.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub text: Option<String>, // Raw smartctl output, for NVMe and SAS disks.
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SmartType {
+    Ata,
+    Text,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SmartAttribute {
+    #[serde(deserialize_with = "crate::deserializers::number_or_str")]
+    pub id: u16,
+    pub name: String,
+    #[serde(default, deserialize_with = "crate::deserializers::lenient_number")]
+    pub value: Option<u64>, // Normalized current value.
+    #[serde(default, deserialize_with = "crate::deserializers::lenient_number")]
+    pub worst: Option<u64>,
+    #[serde(default, deserialize_with = "crate::deserializers::lenient_number")]
+    pub threshold: Option<u64>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub fail: Option<String>, // When the attribute failed, '-' if never.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub flags: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub raw: Option<String>, // Raw value as reported by the drive.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ZfsPool {
+    pub name: String,
+    pub size: u64,
+    pub alloc: u64,
+    pub free: u64,
+    pub frag: u64, // Fragmentation in percent.
+    pub dedup: f64,
+    pub health: String,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ZfsRaidLevel {
+    Single,
+    Mirror,
+    Raid10,
+    RaidZ,
+    RaidZ2,
+    RaidZ3,
+    #[serde(rename = "draid")]
+    DRaid,
+    #[serde(rename = "draid2")]
+    DRaid2,
+    #[serde(rename = "draid3")]
+    DRaid3,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ZfsCompression {
+    On,
+    Off,
+    Gzip,
+    Lz4,
+    Lzjb,
+    Zle,
+    Zstd,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LvmVolumeGroups {
+    #[serde(default)]
+    pub children: Vec<LvmVolumeGroup>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LvmVolumeGroup {
+    pub name: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub free: u64,
+    #[serde(default)]
+    pub children: Vec<LvmPhysicalVolume>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LvmPhysicalVolume {
+    pub name: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub free: u64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LvmThinPool {
+    pub lv: String,         // The name of the thinpool.
+    pub vg: String,         // The associated volume group.
+    pub lv_size: u64,       // The size of the thinpool in bytes.
+    pub used: u64,          // The used bytes of the thinpool.
+    pub metadata_size: u64, // The size of the metadata lv in bytes.
+    pub metadata_used: u64, // The used bytes of the metadata lv.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DirectoryMount {
+    pub path: String,   // The mount path.
+    pub device: String, // The mounted device.
+    #[serde(rename = "type")]
+    pub filesystem: String, // The filesystem type.
+    pub options: String, // The mount options.
+    pub unitfile: String, // The path of the mount unit.
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Filesystem {
+    Ext4,
+    Xfs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Disk, DiskType, SmartData, SmartType};
+
+    #[test]
+    pub fn test_disk_list() {
+        let disks: Vec<Disk> = serde_json::from_str(
+            r#"[{"devpath":"/dev/nvme0n1","type":"nvme","size":1000204886016,"gpt":1,"mounted":0,"used":"LVM","health":"PASSED","wearout":97,"model":"Samsung SSD 980 PRO 1TB","serial":"S5GXNF0R123456","vendor":"unknown","wwn":"eui.002538b111b2c3d4","rpm":0,"by_id_link":"/dev/disk/by-id/nvme-Samsung_SSD_980_PRO_1TB_S5GXNF0R123456","osdid":-1},{"devpath":"/dev/sda","type":"hdd","size":4000787030016,"gpt":0,"health":"UNKNOWN","wearout":"N/A","rpm":7200,"osdid":-1},{"devpath":"/dev/sr0","type":"cdrom","size":0}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(disks[0].disk_type, DiskType::Nvme);
+        assert!(disks[0].gpt);
+        assert_eq!(disks[0].used.as_deref(), Some("LVM"));
+        assert_eq!(disks[0].wearout, Some(97));
+
+        assert_eq!(disks[1].disk_type, DiskType::Hdd);
+        assert_eq!(disks[1].wearout, None);
+        assert_eq!(disks[1].rpm, Some(7200));
+
+        assert_eq!(disks[2].disk_type, DiskType::Unknown);
+    }
+
+    #[test]
+    pub fn test_smart_text() {
+        let smart: SmartData = serde_json::from_str(
+            r#"{"health":"PASSED","type":"text","text":"Critical Warning: 0x00\nTemperature: 38 Celsius\n"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(smart.smart_type, Some(SmartType::Text));
+        assert!(smart.attributes.is_empty());
+        assert!(smart.text.unwrap().starts_with("Critical Warning"));
+    }
+}
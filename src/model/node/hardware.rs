@@ -0,0 +1,141 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Deserialize, Debug)]
+pub struct PciDevice {
+    pub id: String, // The PCI ID, e.g. "0000:01:00.0".
+    #[serde(deserialize_with = "crate::deserializers::hex_number")]
+    pub class: u32, // The PCI Class of the device.
+    #[serde(deserialize_with = "crate::deserializers::hex_number")]
+    pub vendor: u16, // The Vendor ID.
+    #[serde(deserialize_with = "crate::deserializers::hex_number")]
+    pub device: u16, // The Device ID.
+    #[serde(deserialize_with = "iommu_group")]
+    pub iommugroup: Option<u32>, // The IOMMU group, if IOMMU is enabled.
+    #[serde(default, deserialize_with = "crate::deserializers::option_hex_number")]
+    pub subsystem_vendor: Option<u16>, // The Subsystem Vendor ID.
+    #[serde(default, deserialize_with = "crate::deserializers::option_hex_number")]
+    pub subsystem_device: Option<u16>, // The Subsystem Device ID.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub vendor_name: Option<String>, // Only with verbose.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub device_name: Option<String>, // Only with verbose.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub subsystem_vendor_name: Option<String>, // Only with verbose.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub subsystem_device_name: Option<String>, // Only with verbose.
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub mdev: bool, // If set, marks that the device is capable of creating mediated devices.
+}
+
+impl PciDevice {
+    /// The base class code, e.g. 0x03 for display controllers.
+    pub fn base_class(&self) -> u8 {
+        (self.class >> 16) as u8
+    }
+
+    pub fn is_gpu(&self) -> bool {
+        self.base_class() == 0x03
+    }
+
+    pub fn is_network(&self) -> bool {
+        self.base_class() == 0x02
+    }
+}
+
+// Proxmox reports -1 when IOMMU is not enabled
+fn iommu_group<'de, D: Deserializer<'de>>(d: D) -> Result<Option<u32>, D::Error> {
+    let group = i64::deserialize(d)?;
+    Ok(u32::try_from(group).ok())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MediatedDeviceType {
+    #[serde(rename = "type")]
+    pub mdev_type: String, // The name of the mdev type.
+    pub available: u32, // The number of still available instances of this type.
+    pub description: String, // Additional description of the type.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub name: Option<String>, // A human readable name for the type.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UsbDevice {
+    pub busnum: u32,
+    pub devnum: u32,
+    pub port: u32,
+    pub level: u32,
+    #[serde(deserialize_with = "crate::deserializers::number_or_str")]
+    pub class: u32,
+    #[serde(deserialize_with = "crate::deserializers::hex_number")]
+    pub vendid: u16, // The Vendor ID.
+    #[serde(deserialize_with = "crate::deserializers::hex_number")]
+    pub prodid: u16, // The Product ID.
+    #[serde(deserialize_with = "crate::deserializers::number_or_str")]
+    pub speed: f64, // Speed in Mbit/s.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub manufacturer: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub product: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub serial: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub usbpath: Option<String>,
+}
+
+impl UsbDevice {
+    /// The `vendor:product` id as used in USB passthrough configuration.
+    pub fn host_id(&self) -> String {
+        format!("{:04x}:{:04x}", self.vendid, self.prodid)
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct PciFilter {
+    #[serde(rename = "pci-class-blacklist")]
+    pub class_blacklist: Option<String>, // A list of blacklisted PCI classes, separated by ';'. Defaults to "05;06;0b".
+    pub verbose: Option<bool>, // If disabled, does only print the PCI IDs. Otherwise, additional information like vendor and device will be returned.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PciDevice, UsbDevice};
+
+    #[test]
+    pub fn test_hardware_ids() {
+        let device: PciDevice = serde_json::from_str(
+            r#"{
+                "id": "0000:01:00.0",
+                "class": "0x030000",
+                "vendor": "0x10de",
+                "device": "0x1eb8",
+                "iommugroup": 14,
+                "subsystem_vendor": "0x10de",
+                "subsystem_device": "0x12a2",
+                "vendor_name": "NVIDIA Corporation",
+                "mdev": 1
+            }"#,
+        )
+        .unwrap();
+
+        assert!(device.is_gpu());
+        assert_eq!(device.vendor, 0x10de);
+        assert_eq!(device.iommugroup, Some(14));
+        assert_eq!(device.subsystem_device, Some(0x12a2));
+        assert!(device.mdev);
+
+        let device: PciDevice = serde_json::from_str(
+            r#"{"id": "0000:00:19.0", "class": "0x020000", "vendor": "0x8086", "device": "0x153a", "iommugroup": -1}"#,
+        )
+        .unwrap();
+
+        assert!(device.is_network());
+        assert_eq!(device.iommugroup, None);
+
+        let device: UsbDevice = serde_json::from_str(
+            r#"{"busnum": 1, "devnum": 3, "port": 2, "level": 1, "class": 0, "vendid": "0x046d", "prodid": "c52b", "speed": "12"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(device.host_id(), "046d:c52b");
+    }
+}
@@ -0,0 +1,99 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A numbered line of a log, as returned by the syslog and firewall log endpoints.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LogEntry {
+    #[serde(rename = "n")]
+    pub line: u64, // Line number.
+    #[serde(rename = "t")]
+    pub text: String, // Line text.
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct SyslogFilter {
+    pub limit: Option<u64>, // Only list this amount of lines.
+    pub start: Option<u64>, // List lines beginning from this offset.
+    #[serde(serialize_with = "serialize_datetime")]
+    pub since: Option<NaiveDateTime>, // Display all log since this date-time string.
+    #[serde(serialize_with = "serialize_datetime")]
+    pub until: Option<NaiveDateTime>, // Display all log until this date-time string.
+    pub service: Option<String>, // Service ID, e.g. 'pveproxy'.
+}
+
+fn serialize_datetime<S: Serializer>(
+    value: &Option<NaiveDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_str(&value.format("%Y-%m-%d %H:%M:%S").to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct JournalFilter {
+    pub since: Option<i64>, // Display all log since this UNIX epoch. Conflicts with 'startcursor'.
+    pub until: Option<i64>, // Display all log until this UNIX epoch. Conflicts with 'endcursor'.
+    #[serde(rename = "lastentries")]
+    pub last_entries: Option<u64>, // Limit to the last X lines. Conflicts with a range.
+    #[serde(rename = "startcursor")]
+    pub start_cursor: Option<String>, // Start after the given Cursor. Conflicts with 'since'.
+    #[serde(rename = "endcursor")]
+    pub end_cursor: Option<String>, // End before the given Cursor. Conflicts with 'until'.
+}
+
+/// Journal lines, framed by the cursors of the first and last entry.
+#[derive(Debug, Default)]
+pub struct JournalPage {
+    pub start_cursor: Option<String>,
+    pub end_cursor: Option<String>,
+    pub lines: Vec<String>,
+}
+
+impl From<Vec<String>> for JournalPage {
+    // The first and last line returned by Proxmox are the cursors, when there are any entries
+    fn from(mut lines: Vec<String>) -> Self {
+        let is_cursor = |line: &String| line.starts_with("s=");
+
+        let end_cursor = match lines.last() {
+            Some(line) if is_cursor(line) && lines.len() > 1 => lines.pop(),
+            _ => None,
+        };
+        let start_cursor = match lines.first() {
+            Some(line) if is_cursor(line) => Some(lines.remove(0)),
+            _ => None,
+        };
+
+        Self {
+            end_cursor: end_cursor.or_else(|| start_cursor.clone()),
+            start_cursor,
+            lines,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JournalPage;
+
+    #[test]
+    pub fn test_journal_page() {
+        let page = JournalPage::from(vec![
+            "s=abc;i=1".to_string(),
+            "Oct 18 10:00:00 pve1 pveproxy[1]: starting".to_string(),
+            "Oct 18 10:00:01 pve1 pveproxy[1]: started".to_string(),
+            "s=abc;i=2".to_string(),
+        ]);
+
+        assert_eq!(page.start_cursor.as_deref(), Some("s=abc;i=1"));
+        assert_eq!(page.end_cursor.as_deref(), Some("s=abc;i=2"));
+        assert_eq!(page.lines.len(), 2);
+
+        let page = JournalPage::from(vec!["s=abc;i=2".to_string()]);
+        assert_eq!(page.end_cursor.as_deref(), Some("s=abc;i=2"));
+        assert!(page.lines.is_empty());
+
+        assert!(JournalPage::from(vec![]).end_cursor.is_none());
+    }
+}
@@ -14,6 +14,7 @@ pub struct Parameters<'a> {
     pub snapname: Option<&'a str>, // The name of the snapshot.
     pub storage: Option<&'a str>, // Target storage for full clone.
     pub target: Option<NodeId>, // Target node. Only allowed if the original VM is on shared storage.
+    pub check_feature: bool, // Check template-ness and storage support before starting the clone task.
 }
 
 impl<'a> Default for Parameters<'a> {
@@ -28,6 +29,7 @@ impl<'a> Default for Parameters<'a> {
             snapname: Default::default(),
             storage: Default::default(),
             target: Default::default(),
+            check_feature: Default::default(),
         }
     }
 }
@@ -4,21 +4,33 @@ use crate::model::{Architecture, OSType};
 
 #[derive(Deserialize, Debug)]
 pub struct LXCConfiguration {
-    pub digest: String,
+    #[serde(default)]
+    pub digest: String, // Not part of configurations extracted from backups.
 
     #[serde(default)]
     pub arch: Option<Architecture>,
     #[serde(rename = "cmode", default)]
     pub console_mode: Option<ConsoleMode>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
     pub console: Option<bool>,
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
     pub cores: Option<u64>,
-    #[serde(default, rename = "cpulimit")]
+    #[serde(
+        default,
+        rename = "cpulimit",
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
     pub cpu_limit: Option<f64>,
-    #[serde(default, rename = "cpuunits")]
+    #[serde(
+        default,
+        rename = "cpuunits",
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
     pub cpu_units: Option<u64>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
     pub debug: Option<bool>,
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
     pub description: Option<String>,
@@ -30,31 +42,40 @@ pub struct LXCConfiguration {
     pub hostname: Option<String>,
     #[serde(default)]
     pub lxc: Vec<Vec<String>>,
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
     pub memory: Option<u64>,
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
     pub nameserver: Option<String>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
     pub onboot: Option<bool>,
     #[serde(default)]
     pub ostype: Option<OSType>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
     pub protection: Option<bool>,
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
     pub searchdomain: Option<String>,
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
     pub startup: Option<String>,
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
     pub swap: Option<u64>,
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
     pub tags: Option<String>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
     pub template: Option<bool>,
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
     pub timezone: Option<String>,
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
     pub tty: Option<u64>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
     pub unprivileged: Option<bool>,
 }
 
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Feature {
+    Snapshot,
+    Clone,
+    Copy,
+}
+
+impl Feature {
+    /// The storage feature a clone needs, `None` if a linked clone of a normal container is requested.
+    ///
+    /// Templates are linked-cloned unless a full clone is requested, normal containers are always copied.
+    pub fn for_clone(template: bool, full: Option<bool>) -> Option<Self> {
+        match (template, full) {
+            (true, Some(true)) => Some(Self::Copy),
+            (true, _) => Some(Self::Clone),
+            (false, Some(false)) => None,
+            (false, _) => Some(Self::Copy),
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Snapshot => write!(f, "Snapshot"),
+            Self::Clone => write!(f, "Linked clone"),
+            Self::Copy => write!(f, "Full clone"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FeatureAvailability {
+    #[serde(
+        rename = "hasFeature",
+        deserialize_with = "crate::deserializers::int_bool"
+    )]
+    pub has_feature: bool, // Whether the feature is available on the storages of the container.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Feature;
+
+    #[test]
+    pub fn test_feature_for_clone() {
+        assert_eq!(Feature::for_clone(true, None), Some(Feature::Clone));
+        assert_eq!(Feature::for_clone(true, Some(false)), Some(Feature::Clone));
+        assert_eq!(Feature::for_clone(true, Some(true)), Some(Feature::Copy));
+        assert_eq!(Feature::for_clone(false, None), Some(Feature::Copy));
+        assert_eq!(Feature::for_clone(false, Some(true)), Some(Feature::Copy));
+        assert_eq!(Feature::for_clone(false, Some(false)), None);
+    }
+}
@@ -4,7 +4,9 @@ use super::VMId;
 
 pub mod clone;
 pub mod config;
+pub mod feature;
 pub mod interfaces;
+pub mod restore;
 
 #[derive(Deserialize, Debug)]
 pub struct LXC {
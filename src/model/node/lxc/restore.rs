@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// Parameters to restore a container from a vzdump backup archive.
+///
+/// `overrides` are passed as-is and replace the values from the backed up configuration,
+/// e.g. `rootfs` => `local-lvm:8` to move the root filesystem to another storage.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct RestoreParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>, // Default Storage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique: Option<bool>, // Assign a unique random ethernet address.
+    #[serde(
+        rename = "ignore-unpack-errors",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ignore_unpack_errors: Option<bool>, // Ignore errors when extracting the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>, // Allow to overwrite existing container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<bool>, // Start the CT after its creation finished successfully.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>, // Add the VM to the specified pool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bwlimit: Option<u64>, // Override I/O bandwidth limit (in KiB/s).
+    #[serde(flatten)]
+    pub overrides: BTreeMap<String, String>, // Configuration keys to set instead of the backed up values.
+}
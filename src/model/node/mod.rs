@@ -3,11 +3,24 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 
 pub mod aplinfo;
+pub mod apt;
+pub mod capabilities;
+pub mod certificates;
 pub mod config;
+pub mod console;
+pub mod disks;
 pub mod dns;
 pub mod execute;
+pub mod hardware;
 pub mod hosts;
+pub mod logs;
 pub mod netstat;
+pub mod network;
+pub mod scan;
+pub mod services;
+pub mod status;
+pub mod storage;
+pub mod subscription;
 pub mod time;
 pub mod url_metadata;
 
@@ -0,0 +1,376 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum InterfaceType {
+    #[serde(rename = "bridge")]
+    Bridge,
+    #[serde(rename = "bond")]
+    Bond,
+    #[serde(rename = "eth")]
+    Eth,
+    #[serde(rename = "alias")]
+    Alias,
+    #[serde(rename = "vlan")]
+    Vlan,
+    OVSBridge,
+    OVSBond,
+    OVSPort,
+    OVSIntPort,
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum TypeFilter {
+    #[serde(rename = "bridge")]
+    Bridge,
+    #[serde(rename = "bond")]
+    Bond,
+    #[serde(rename = "eth")]
+    Eth,
+    #[serde(rename = "alias")]
+    Alias,
+    #[serde(rename = "vlan")]
+    Vlan,
+    OVSBridge,
+    OVSBond,
+    OVSPort,
+    OVSIntPort,
+    #[serde(rename = "any_bridge")]
+    AnyBridge,
+    #[serde(rename = "any_local_bridge")]
+    AnyLocalBridge,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum BondMode {
+    #[serde(rename = "balance-rr")]
+    BalanceRR,
+    #[serde(rename = "active-backup")]
+    ActiveBackup,
+    #[serde(rename = "balance-xor")]
+    BalanceXor,
+    #[serde(rename = "broadcast")]
+    Broadcast,
+    #[serde(rename = "802.3ad")]
+    LACP,
+    #[serde(rename = "balance-tlb")]
+    BalanceTlb,
+    #[serde(rename = "balance-alb")]
+    BalanceAlb,
+    #[serde(rename = "balance-slb")]
+    BalanceSlb,
+    #[serde(rename = "lacp-balance-slb")]
+    LACPBalanceSlb,
+    #[serde(rename = "lacp-balance-tcp")]
+    LACPBalanceTcp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum BondHashPolicy {
+    #[serde(rename = "layer2")]
+    Layer2,
+    #[serde(rename = "layer2+3")]
+    Layer23,
+    #[serde(rename = "layer3+4")]
+    Layer34,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Method {
+    Static,
+    Manual,
+    Dhcp,
+    Auto,
+    Loopback,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Interface {
+    pub iface: String,
+    #[serde(rename = "type")]
+    pub interface_type: InterfaceType,
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub active: bool, // Interface is up.
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub autostart: bool, // Automatically start interface on boot.
+    #[serde(default, deserialize_with = "crate::deserializers::int_bool")]
+    pub exists: bool, // Interface exists on the system, not only in the configuration.
+    #[serde(default)]
+    pub families: Vec<String>, // Address families, 'inet' and/or 'inet6'.
+    #[serde(default)]
+    pub method: Option<Method>,
+    #[serde(default)]
+    pub method6: Option<Method>,
+    #[serde(default)]
+    pub priority: Option<u64>, // Order of the interface in /etc/network/interfaces.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub address: Option<String>, // IP address.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub netmask: Option<String>, // Network mask.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub cidr: Option<String>, // IPv4 CIDR.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub gateway: Option<String>, // Default gateway address.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub address6: Option<String>, // IP address.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
+    pub netmask6: Option<u8>, // Network mask.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub cidr6: Option<String>, // IPv6 CIDR.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub gateway6: Option<String>, // Default ipv6 gateway address.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub bridge_ports: Option<String>, // Specify the interfaces you want to add to your bridge.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub bridge_vlan_aware: Option<bool>, // Enable bridge vlan support.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub bridge_vids: Option<String>, // Specify the allowed VLANs. For example: '2 4 100-200'.
+    #[serde(default)]
+    pub bond_mode: Option<BondMode>, // Bonding mode.
+    #[serde(
+        default,
+        rename = "bond-primary",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub bond_primary: Option<String>, // Specify the primary interface for active-backup bond.
+    #[serde(default)]
+    pub bond_xmit_hash_policy: Option<BondHashPolicy>, // Selects the transmit hash policy to use for slave selection in balance-xor and 802.3ad modes.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub slaves: Option<String>, // Specify the interfaces used by the bonding device.
+    #[serde(
+        default,
+        rename = "vlan-id",
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
+    pub vlan_id: Option<u16>, // vlan-id for a custom named vlan interface (ifupdown2 only).
+    #[serde(
+        default,
+        rename = "vlan-raw-device",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub vlan_raw_device: Option<String>, // Specify the raw interface for the vlan interface.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
+    pub mtu: Option<u16>, // MTU.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comments: Option<String>, // Comments
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comments6: Option<String>, // Comments
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub ovs_bonds: Option<String>, // Specify the interfaces used by the bonding device.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub ovs_bridge: Option<String>, // The OVS bridge associated with a OVS port. This is required when you create an OVS port.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub ovs_options: Option<String>, // OVS interface options.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub ovs_ports: Option<String>, // Specify the interfaces you want to add to your bridge.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserializers::option_number_or_str"
+    )]
+    pub ovs_tag: Option<u16>, // Specify a VLan tag (used by OVSPort, OVSIntPort, OVSBond)
+}
+
+/// Parameters to create or update an interface in /etc/network/interfaces.new.
+/// ```
+/// # use proxmox_api::model::node::network::{InterfaceParameters, InterfaceType};
+/// let parameters = InterfaceParameters {
+///     cidr: Some("10.0.0.2/24".into()),
+///     bridge_ports: Some("eno1".into()),
+///     autostart: Some(true),
+///     ..InterfaceParameters::new("vmbr1", InterfaceType::Bridge)
+/// };
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct InterfaceParameters {
+    pub iface: String, // Network interface name.
+    #[serde(rename = "type")]
+    pub interface_type: InterfaceType, // Network interface type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autostart: Option<bool>, // Automatically start interface on boot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>, // IP address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub netmask: Option<String>, // Network mask.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr: Option<String>, // IPv4 CIDR.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>, // Default gateway address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address6: Option<String>, // IP address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub netmask6: Option<u8>, // Network mask.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr6: Option<String>, // IPv6 CIDR.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway6: Option<String>, // Default ipv6 gateway address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge_ports: Option<String>, // Specify the interfaces you want to add to your bridge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge_vlan_aware: Option<bool>, // Enable bridge vlan support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge_vids: Option<String>, // Specify the allowed VLANs. For example: '2 4 100-200'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bond_mode: Option<BondMode>, // Bonding mode.
+    #[serde(rename = "bond-primary", skip_serializing_if = "Option::is_none")]
+    pub bond_primary: Option<String>, // Specify the primary interface for active-backup bond.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bond_xmit_hash_policy: Option<BondHashPolicy>, // Selects the transmit hash policy to use for slave selection in balance-xor and 802.3ad modes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slaves: Option<String>, // Specify the interfaces used by the bonding device.
+    #[serde(rename = "vlan-id", skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<u16>, // vlan-id for a custom named vlan interface (ifupdown2 only).
+    #[serde(rename = "vlan-raw-device", skip_serializing_if = "Option::is_none")]
+    pub vlan_raw_device: Option<String>, // Specify the raw interface for the vlan interface.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u16>, // MTU.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>, // Comments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments6: Option<String>, // Comments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ovs_bonds: Option<String>, // Specify the interfaces used by the bonding device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ovs_bridge: Option<String>, // The OVS bridge associated with a OVS port. This is required when you create an OVS port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ovs_options: Option<String>, // OVS interface options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ovs_ports: Option<String>, // Specify the interfaces you want to add to your bridge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ovs_tag: Option<u16>, // Specify a VLan tag (used by OVSPort, OVSIntPort, OVSBond)
+}
+
+impl InterfaceParameters {
+    pub fn new(iface: impl Into<String>, interface_type: InterfaceType) -> Self {
+        Self {
+            iface: iface.into(),
+            interface_type,
+            autostart: None,
+            address: None,
+            netmask: None,
+            cidr: None,
+            gateway: None,
+            address6: None,
+            netmask6: None,
+            cidr6: None,
+            gateway6: None,
+            bridge_ports: None,
+            bridge_vlan_aware: None,
+            bridge_vids: None,
+            bond_mode: None,
+            bond_primary: None,
+            bond_xmit_hash_policy: None,
+            slaves: None,
+            vlan_id: None,
+            vlan_raw_device: None,
+            mtu: None,
+            comments: None,
+            comments6: None,
+            ovs_bonds: None,
+            ovs_bridge: None,
+            ovs_options: None,
+            ovs_ports: None,
+            ovs_tag: None,
+        }
+    }
+}
+
+/// Interface settings that can be removed with an update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterfaceField {
+    Autostart,
+    Address,
+    Netmask,
+    Cidr,
+    Gateway,
+    Address6,
+    Netmask6,
+    Cidr6,
+    Gateway6,
+    BridgePorts,
+    BridgeVlanAware,
+    BridgeVids,
+    BondMode,
+    BondPrimary,
+    BondXmitHashPolicy,
+    Slaves,
+    VlanId,
+    VlanRawDevice,
+    Mtu,
+    Comments,
+    Comments6,
+    OvsBonds,
+    OvsBridge,
+    OvsOptions,
+    OvsPorts,
+    OvsTag,
+}
+
+impl Display for InterfaceField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Autostart => write!(f, "autostart"),
+            Self::Address => write!(f, "address"),
+            Self::Netmask => write!(f, "netmask"),
+            Self::Cidr => write!(f, "cidr"),
+            Self::Gateway => write!(f, "gateway"),
+            Self::Address6 => write!(f, "address6"),
+            Self::Netmask6 => write!(f, "netmask6"),
+            Self::Cidr6 => write!(f, "cidr6"),
+            Self::Gateway6 => write!(f, "gateway6"),
+            Self::BridgePorts => write!(f, "bridge_ports"),
+            Self::BridgeVlanAware => write!(f, "bridge_vlan_aware"),
+            Self::BridgeVids => write!(f, "bridge_vids"),
+            Self::BondMode => write!(f, "bond_mode"),
+            Self::BondPrimary => write!(f, "bond-primary"),
+            Self::BondXmitHashPolicy => write!(f, "bond_xmit_hash_policy"),
+            Self::Slaves => write!(f, "slaves"),
+            Self::VlanId => write!(f, "vlan-id"),
+            Self::VlanRawDevice => write!(f, "vlan-raw-device"),
+            Self::Mtu => write!(f, "mtu"),
+            Self::Comments => write!(f, "comments"),
+            Self::Comments6 => write!(f, "comments6"),
+            Self::OvsBonds => write!(f, "ovs_bonds"),
+            Self::OvsBridge => write!(f, "ovs_bridge"),
+            Self::OvsOptions => write!(f, "ovs_options"),
+            Self::OvsPorts => write!(f, "ovs_ports"),
+            Self::OvsTag => write!(f, "ovs_tag"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interface;
+
+    #[test]
+    pub fn test_interface_string_numbers() {
+        let interface: Interface = serde_json::from_str(
+            r#"{"iface":"vlan20","type":"vlan","families":["inet","inet6"],"method":"static","method6":"static","address6":"fd00::2","netmask6":"64","vlan-id":"20","vlan-raw-device":"eno1","mtu":"9000","ovs_tag":"","priority":7}"#,
+        )
+        .unwrap();
+
+        assert_eq!(interface.netmask6, Some(64));
+        assert_eq!(interface.vlan_id, Some(20));
+        assert_eq!(interface.mtu, Some(9000));
+        assert_eq!(interface.ovs_tag, None);
+
+        let interface: Interface =
+            serde_json::from_str(r#"{"iface":"eno1","type":"eth","mtu":1500}"#).unwrap();
+        assert_eq!(interface.mtu, Some(1500));
+
+        assert!(serde_json::from_str::<Interface>(
+            r#"{"iface":"eno1","type":"eth","mtu":"jumbo"}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct NfsExport {
+    pub path: String,    // The exported path.
+    pub options: String, // NFS export options.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CifsShare {
+    pub share: String, // The cifs share name.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub description: Option<String>, // Descriptive text from server.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IscsiTarget {
+    pub target: String, // The iSCSI target name.
+    pub portal: String, // The iSCSI portal name.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LvmVolumeGroup {
+    pub vg: String, // The LVM logical volume group name.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LvmThinPool {
+    pub lv: String, // The LVM Thin Pool name (LVM logical volume).
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ZfsPool {
+    pub pool: String, // ZFS pool name.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PbsDatastore {
+    pub store: String, // The datastore name.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub comment: Option<String>, // Comment from server.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CifsShare, IscsiTarget, PbsDatastore};
+
+    #[test]
+    pub fn test_scan_results() {
+        let shares: Vec<CifsShare> = serde_json::from_str(
+            r#"[{"share":"backups","description":"Backup share"},{"share":"IPC$","description":""}]"#,
+        )
+        .unwrap();
+        assert_eq!(shares[0].description.as_deref(), Some("Backup share"));
+        assert_eq!(shares[1].description, None);
+
+        let targets: Vec<IscsiTarget> = serde_json::from_str(
+            r#"[{"target":"iqn.2005-10.org.freenas.ctl:pve","portal":"10.0.0.5:3260"}]"#,
+        )
+        .unwrap();
+        assert_eq!(targets[0].portal, "10.0.0.5:3260");
+
+        let stores: Vec<PbsDatastore> =
+            serde_json::from_str(r#"[{"store":"main"},{"store":"offsite","comment":"S3"}]"#)
+                .unwrap();
+        assert_eq!(stores[0].comment, None);
+        assert_eq!(stores[1].comment.as_deref(), Some("S3"));
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "String", into = "String")]
+pub enum ServiceName {
+    Chrony,
+    Corosync,
+    Cron,
+    Ksmtuned,
+    Postfix,
+    PveCluster,
+    PveFirewall,
+    PveHaCrm,
+    PveHaLrm,
+    PveDaemon,
+    PveFwLogger,
+    PveProxy,
+    PveScheduler,
+    PveStatd,
+    SpiceProxy,
+    Sshd,
+    Syslog,
+    SystemdJournald,
+    SystemdTimesyncd,
+    Other(String), // Any service not known to this crate.
+}
+
+impl From<&str> for ServiceName {
+    fn from(value: &str) -> Self {
+        match value {
+            "chrony" => Self::Chrony,
+            "corosync" => Self::Corosync,
+            "cron" => Self::Cron,
+            "ksmtuned" => Self::Ksmtuned,
+            "postfix" => Self::Postfix,
+            "pve-cluster" => Self::PveCluster,
+            "pve-firewall" => Self::PveFirewall,
+            "pve-ha-crm" => Self::PveHaCrm,
+            "pve-ha-lrm" => Self::PveHaLrm,
+            "pvedaemon" => Self::PveDaemon,
+            "pvefw-logger" => Self::PveFwLogger,
+            "pveproxy" => Self::PveProxy,
+            "pvescheduler" => Self::PveScheduler,
+            "pvestatd" => Self::PveStatd,
+            "spiceproxy" => Self::SpiceProxy,
+            "sshd" => Self::Sshd,
+            "syslog" => Self::Syslog,
+            "systemd-journald" => Self::SystemdJournald,
+            "systemd-timesyncd" => Self::SystemdTimesyncd,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for ServiceName {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<ServiceName> for String {
+    fn from(value: ServiceName) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for ServiceName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Chrony => write!(f, "chrony"),
+            Self::Corosync => write!(f, "corosync"),
+            Self::Cron => write!(f, "cron"),
+            Self::Ksmtuned => write!(f, "ksmtuned"),
+            Self::Postfix => write!(f, "postfix"),
+            Self::PveCluster => write!(f, "pve-cluster"),
+            Self::PveFirewall => write!(f, "pve-firewall"),
+            Self::PveHaCrm => write!(f, "pve-ha-crm"),
+            Self::PveHaLrm => write!(f, "pve-ha-lrm"),
+            Self::PveDaemon => write!(f, "pvedaemon"),
+            Self::PveFwLogger => write!(f, "pvefw-logger"),
+            Self::PveProxy => write!(f, "pveproxy"),
+            Self::PveScheduler => write!(f, "pvescheduler"),
+            Self::PveStatd => write!(f, "pvestatd"),
+            Self::SpiceProxy => write!(f, "spiceproxy"),
+            Self::Sshd => write!(f, "sshd"),
+            Self::Syslog => write!(f, "syslog"),
+            Self::SystemdJournald => write!(f, "systemd-journald"),
+            Self::SystemdTimesyncd => write!(f, "systemd-timesyncd"),
+            Self::Other(name) => f.write_str(name),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Service {
+    pub service: ServiceName,
+    pub name: String,
+    #[serde(default, rename = "desc")]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub state: Option<String>, // Sub state, e.g. 'running' or 'dead'.
+    #[serde(
+        default,
+        rename = "active-state",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub active_state: Option<String>, // e.g. 'active', 'inactive' or 'failed'.
+    #[serde(
+        default,
+        rename = "unit-state",
+        deserialize_with = "crate::deserializers::non_empty_str"
+    )]
+    pub unit_state: Option<String>, // e.g. 'enabled', 'disabled' or 'masked'.
+}
+
+impl Service {
+    pub fn is_running(&self) -> bool {
+        self.state.as_deref() == Some("running")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServiceName;
+
+    #[test]
+    pub fn test_service_name() {
+        assert_eq!(ServiceName::from("pveproxy"), ServiceName::PveProxy);
+        assert_eq!(ServiceName::PveHaLrm.to_string(), "pve-ha-lrm");
+        assert_eq!(
+            ServiceName::from("ceph-mon@pve1"),
+            ServiceName::Other("ceph-mon@pve1".into())
+        );
+    }
+}
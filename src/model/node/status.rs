@@ -0,0 +1,172 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Deserialize, Debug)]
+pub struct NodeStatus {
+    pub cpu: f64,         // CPU utilization.
+    pub cpuinfo: CpuInfo, // CPU information.
+    #[serde(default)]
+    pub wait: Option<f64>, // IO delay.
+    #[serde(default)]
+    pub idle: Option<f64>, // Idle time.
+    #[serde(deserialize_with = "load_average")]
+    pub loadavg: [f64; 3], // Load average over 1, 5 and 15 minutes.
+    pub memory: Usage,    // Memory usage in bytes.
+    pub swap: Usage,      // Swap usage in bytes.
+    pub rootfs: FilesystemUsage, // Root filesystem usage in bytes.
+    #[serde(default)]
+    pub ksm: Option<Ksm>, // Kernel samepage merging.
+    pub uptime: u64,      // Uptime in seconds.
+    pub kversion: String, // Kernel version string.
+    #[serde(default, rename = "current-kernel")]
+    pub current_kernel: Option<Kernel>, // The currently running kernel.
+    pub pveversion: String, // The pve-manager version string.
+    #[serde(default, rename = "boot-info")]
+    pub boot_info: Option<BootInfo>, // How the node was booted.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CpuInfo {
+    pub model: String, // CPU model name.
+    pub cpus: u32,     // Number of logical CPUs.
+    pub cores: u32,    // Number of cores per socket.
+    pub sockets: u32,  // Number of sockets.
+    #[serde(deserialize_with = "crate::deserializers::number_or_str")]
+    pub mhz: f64, // Current clock frequency.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub hvm: Option<bool>, // Hardware virtualization support.
+    #[serde(default)]
+    pub flags: String, // CPU flags, separated by spaces.
+    #[serde(default)]
+    pub user_hz: Option<u32>,
+}
+
+impl CpuInfo {
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.split_whitespace().any(|f| f == flag)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Usage {
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,
+}
+
+impl Usage {
+    /// Used share between 0 and 1.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        self.used as f64 / self.total as f64
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct FilesystemUsage {
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,
+    pub avail: u64, // Space available to unprivileged users.
+}
+
+impl FilesystemUsage {
+    /// Used share between 0 and 1.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        self.used as f64 / self.total as f64
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Ksm {
+    pub shared: u64, // Memory shared in bytes.
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Kernel {
+    pub sysname: String, // OS kernel name (e.g., "Linux")
+    pub release: String, // OS kernel release (e.g., "6.8.0")
+    pub version: String, // OS kernel version with build info
+    pub machine: String, // Hardware (architecture) type
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct BootInfo {
+    pub mode: BootMode,
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub secureboot: Option<bool>, // Only set for EFI boots.
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum BootMode {
+    Efi,
+    LegacyBios,
+}
+
+#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerCommand {
+    Reboot,
+    Shutdown,
+}
+
+// Proxmox returns the load average as strings
+fn load_average<'de, D: Deserializer<'de>>(d: D) -> Result<[f64; 3], D::Error> {
+    use serde::de::Error;
+
+    let values: Vec<String> = Vec::deserialize(d)?;
+    let values = values
+        .iter()
+        .map(|value| value.parse::<f64>().map_err(D::Error::custom))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    values
+        .try_into()
+        .map_err(|_| D::Error::custom("expected three load average values"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BootMode, NodeStatus};
+
+    #[test]
+    pub fn test_node_status() {
+        let status: NodeStatus = serde_json::from_str(
+            r##"{
+                "boot-info": {"mode": "efi", "secureboot": 0},
+                "cpu": 0.0123,
+                "cpuinfo": {"cores": 4, "cpus": 8, "flags": "fpu vmx sse", "hvm": "1", "mhz": "3400.000", "model": "Intel(R) Xeon(R)", "sockets": 1, "user_hz": 100},
+                "current-kernel": {"machine": "x86_64", "release": "6.5.11-7-pve", "sysname": "Linux", "version": "#1 SMP PREEMPT_DYNAMIC"},
+                "idle": 0,
+                "ksm": {"shared": 0},
+                "kversion": "Linux 6.5.11-7-pve #1 SMP PREEMPT_DYNAMIC",
+                "loadavg": ["0.10", "0.05", "0.01"],
+                "memory": {"free": 6, "total": 8, "used": 2},
+                "pveversion": "pve-manager/8.1.4/ec5affc9e41f1d79",
+                "rootfs": {"avail": 80, "free": 90, "total": 100, "used": 10},
+                "swap": {"free": 0, "total": 0, "used": 0},
+                "uptime": 1234,
+                "wait": 0.001
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(status.loadavg, [0.10, 0.05, 0.01]);
+        assert_eq!(status.cpuinfo.mhz, 3400.0);
+        assert_eq!(status.cpuinfo.hvm, Some(true));
+        assert!(status.cpuinfo.has_flag("vmx"));
+        assert_eq!(status.memory.ratio(), 0.25);
+        assert_eq!(status.swap.ratio(), 0.0);
+
+        let boot = status.boot_info.unwrap();
+        assert_eq!(boot.mode, BootMode::Efi);
+        assert_eq!(boot.secureboot, Some(false));
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::VMId;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentType {
+    Images,
+    RootDir,
+    VzTmpl,
+    Backup,
+    Iso,
+    Snippets,
+    Import,
+}
+
+impl Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Images => write!(f, "images"),
+            Self::RootDir => write!(f, "rootdir"),
+            Self::VzTmpl => write!(f, "vztmpl"),
+            Self::Backup => write!(f, "backup"),
+            Self::Iso => write!(f, "iso"),
+            Self::Snippets => write!(f, "snippets"),
+            Self::Import => write!(f, "import"),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct StorageContent {
+    pub volid: String,        // Volume identifier.
+    pub content: ContentType, // Content type.
+    pub format: String,       // Format identifier ('raw', 'qcow2', 'subvol', 'iso', 'tgz' ...)
+    pub size: u64,            // Volume size in bytes.
+    #[serde(default)]
+    pub used: Option<u64>, // Used space. Please note that most storage plugins do not report anything useful here.
+    #[serde(default)]
+    pub ctime: Option<i64>, // Creation time (seconds since the UNIX Epoch).
+    #[serde(default)]
+    pub vmid: Option<u64>, // Associated Owner VMID.
+    #[serde(default)]
+    pub subtype: Option<GuestType>, // Guest type of backups.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub notes: Option<String>, // Optional notes. If they contain multiple lines, only the first one is returned here.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub protected: Option<bool>, // Protection status. Currently only supported for backups.
+    #[serde(default, deserialize_with = "crate::deserializers::option_int_bool")]
+    pub encrypted: Option<bool>, // If whole backup is encrypted, value is the fingerprint or '1' if encrypted.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub parent: Option<String>, // Volume identifier of parent (for linked cloned).
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GuestType {
+    Lxc,
+    Qemu,
+    #[serde(other)]
+    Unknown, // Removed but not purged guests.
+}
+
+/// The most recent container backup of `vmid` in a storage content listing.
+pub fn latest_backup<'a>(
+    contents: &'a [StorageContent],
+    vmid: &VMId,
+) -> Option<&'a StorageContent> {
+    contents
+        .iter()
+        .filter(|content| content.content == ContentType::Backup)
+        .filter(|content| match content.subtype {
+            Some(GuestType::Lxc) => true,
+            Some(GuestType::Qemu) => false,
+            // Fall back to the vzdump archive name when the guest type is not reported
+            _ => content.volid.contains("/vzdump-lxc-"),
+        })
+        .filter(|content| content.vmid.is_some_and(|id| id.to_string() == vmid.0))
+        .max_by_key(|content| content.ctime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{latest_backup, StorageContent};
+    use crate::model::node::VMId;
+
+    #[test]
+    pub fn test_latest_backup() {
+        let contents: Vec<StorageContent> = serde_json::from_str(
+            r#"[
+                {"volid": "local:backup/vzdump-lxc-100-2024_01_01-00_00_00.tar.zst", "content": "backup", "format": "tar.zst", "size": 1, "ctime": 1704067200, "vmid": 100, "subtype": "lxc"},
+                {"volid": "local:backup/vzdump-lxc-100-2024_01_02-00_00_00.tar.zst", "content": "backup", "format": "tar.zst", "size": 1, "ctime": 1704153600, "vmid": 100, "subtype": "lxc", "protected": 1},
+                {"volid": "local:backup/vzdump-qemu-100-2024_01_03-00_00_00.vma.zst", "content": "backup", "format": "vma.zst", "size": 1, "ctime": 1704240000, "vmid": 100, "subtype": "qemu"},
+                {"volid": "local:backup/vzdump-lxc-101-2024_01_04-00_00_00.tar.zst", "content": "backup", "format": "tar.zst", "size": 1, "ctime": 1704326400, "vmid": 101, "subtype": "lxc"},
+                {"volid": "local:backup/vzdump-qemu-100-2024_01_05-00_00_00.vma.zst", "content": "backup", "format": "vma.zst", "size": 1, "ctime": 1704412800, "vmid": 100},
+                {"volid": "local:backup/vzdump-qemu-100-2024_01_06-00_00_00.vma.zst", "content": "backup", "format": "vma.zst", "size": 1, "ctime": 1704499200, "vmid": 100, "subtype": "unknown"},
+                {"volid": "local:backup/vzdump-lxc-103-2024_01_07-00_00_00.tar.zst", "content": "backup", "format": "tar.zst", "size": 1, "ctime": 1704585600, "vmid": 103}
+            ]"#,
+        )
+        .unwrap();
+
+        let latest = latest_backup(&contents, &VMId::from("100")).unwrap();
+        assert_eq!(
+            latest.volid,
+            "local:backup/vzdump-lxc-100-2024_01_02-00_00_00.tar.zst"
+        );
+
+        let latest = latest_backup(&contents, &VMId::from("103")).unwrap();
+        assert_eq!(
+            latest.volid,
+            "local:backup/vzdump-lxc-103-2024_01_07-00_00_00.tar.zst"
+        );
+
+        assert!(latest_backup(&contents, &VMId::from("102")).is_none());
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Subscription {
+    pub status: SubscriptionStatus,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub serverid: Option<String>, // The server ID of this node.
+    #[serde(default)]
+    pub sockets: Option<u32>, // Number of sockets covered by the subscription.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub key: Option<String>, // The subscription key.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_parsed")]
+    pub level: Option<SubscriptionLevel>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub productname: Option<String>,
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub regdate: Option<String>, // Registration date.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_parsed")]
+    pub nextduedate: Option<NaiveDate>, // Next due date.
+    #[serde(default)]
+    pub checktime: Option<i64>, // Timestamp of the last check done.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub message: Option<String>, // A more human readable status message.
+    #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
+    pub url: Option<String>,
+}
+
+impl Subscription {
+    pub fn is_active(&self) -> bool {
+        self.status == SubscriptionStatus::Active
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionStatus {
+    #[serde(alias = "New")]
+    New,
+    #[serde(alias = "NotFound")]
+    NotFound,
+    #[serde(alias = "Active")]
+    Active,
+    #[serde(alias = "Invalid")]
+    Invalid,
+    #[serde(alias = "Expired")]
+    Expired,
+    #[serde(alias = "Suspended")]
+    Suspended,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SubscriptionLevel {
+    Community,
+    Basic,
+    Standard,
+    Premium,
+}
+
+impl std::str::FromStr for SubscriptionLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "c" => Ok(Self::Community),
+            "b" => Ok(Self::Basic),
+            "s" => Ok(Self::Standard),
+            "p" => Ok(Self::Premium),
+            other => Err(format!("unknown subscription level '{other}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{Subscription, SubscriptionLevel, SubscriptionStatus};
+
+    #[test]
+    pub fn test_subscription() {
+        let subscription: Subscription = serde_json::from_str(
+            r#"{
+                "status": "active",
+                "serverid": "0123456789ABCDEF0123456789ABCDEF",
+                "sockets": 2,
+                "key": "pve2c-0123456789",
+                "level": "c",
+                "productname": "Proxmox VE Community Subscription 2 CPUs/year",
+                "regdate": "2023-01-01 00:00:00",
+                "nextduedate": "2024-01-01",
+                "checktime": 1700000000
+            }"#,
+        )
+        .unwrap();
+
+        assert!(subscription.is_active());
+        assert_eq!(subscription.level, Some(SubscriptionLevel::Community));
+        assert_eq!(
+            subscription.nextduedate,
+            NaiveDate::from_ymd_opt(2024, 1, 1)
+        );
+
+        let subscription: Subscription =
+            serde_json::from_str(r#"{"status": "notfound", "serverid": "ABC", "level": ""}"#)
+                .unwrap();
+
+        assert_eq!(subscription.status, SubscriptionStatus::NotFound);
+        assert_eq!(subscription.level, None);
+    }
+}
@@ -0,0 +1,135 @@
+use serde::{Serialize, Serializer};
+
+use crate::model::{
+    node::{NodeId, VMId},
+    Size,
+};
+
+use super::defaults::{Compression, Mode, NotificationPolicy};
+use super::prune::PruneBackups;
+
+/// Options for an ad-hoc backup. Unset fields fall back to the node's vzdump defaults.
+///
+/// ```no_run
+/// # use proxmox_api::model::node::{vzdump::{backup::BackupOptions, defaults::Mode}, VMId};
+/// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+/// # let node = client.node("pve1");
+/// let options = BackupOptions {
+///     mode: Some(Mode::Snapshot),
+///     storage: Some("pbs".to_string()),
+///     ..BackupOptions::guests(vec![VMId::from("100")])
+/// };
+///
+/// let upid = node.vzdump.backup(&options).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct BackupOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "comma_list")]
+    pub vmid: Vec<VMId>, // The ID of the guest system you want to backup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>, // Backup all known guest systems on this host.
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "comma_list")]
+    pub exclude: Vec<VMId>, // Exclude specified guest systems (assumes --all)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>, // Backup all known guest systems included in the specified pool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>, // Backup mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compress: Option<Compression>, // Compress dump file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>, // Store resulting file to this storage.
+    #[serde(rename = "notes-template", skip_serializing_if = "Option::is_none")]
+    pub notes_template: Option<String>, // Template string for generating notes for the backup(s). Supports {{cluster}}, {{guestname}}, {{node}}, and {{vmid}}.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>, // If true, mark backup(s) as protected.
+    #[serde(
+        rename = "prune-backups",
+        skip_serializing_if = "super::prune::is_unset"
+    )]
+    pub prune_backups: Option<PruneBackups>, // Use these retention options instead of those from the storage configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<bool>, // Prune older backups according to 'prune-backups'.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "kib")]
+    pub bwlimit: Option<Size>, // Limit I/O bandwidth per second.
+    #[serde(
+        rename = "notification-policy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub notification_policy: Option<NotificationPolicy>, // Specify when to send a notification
+    #[serde(
+        rename = "notification-target",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub notification_target: Option<String>, // Determine the target to which notifications should be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mailto: Option<String>, // Comma-separated list of email addresses or users that should receive email notifications.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node: Option<NodeId>, // Only run if executed on this node.
+}
+
+impl BackupOptions {
+    /// Backup the given guests.
+    pub fn guests(vmids: Vec<VMId>) -> Self {
+        Self {
+            vmid: vmids,
+            ..Default::default()
+        }
+    }
+
+    /// Backup all guests on the node, except the excluded ones.
+    pub fn all(exclude: Vec<VMId>) -> Self {
+        Self {
+            all: Some(true),
+            exclude,
+            ..Default::default()
+        }
+    }
+}
+
+pub(crate) fn comma_list<S, T>(items: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: std::fmt::Display,
+{
+    let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+    serializer.serialize_str(&items.join(","))
+}
+
+// Bandwidth limits are sent as whole KiB
+pub(crate) fn kib<S: Serializer>(value: &Option<Size>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(size) => serializer.serialize_u64(size.to_kb().round() as u64),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        node::{vzdump::defaults::Mode, VMId},
+        Size,
+    };
+
+    use super::BackupOptions;
+
+    #[test]
+    pub fn test_backup_options() {
+        let options = BackupOptions {
+            mode: Some(Mode::Snapshot),
+            bwlimit: Some(Size::MB(10.0)),
+            ..BackupOptions::guests(vec![VMId::from("100"), VMId::from("101")])
+        };
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({"vmid": "100,101", "mode": "snapshot", "bwlimit": 10240})
+        );
+
+        assert_eq!(
+            serde_json::to_value(BackupOptions::all(vec![VMId::from("200")])).unwrap(),
+            serde_json::json!({"all": true, "exclude": "200"})
+        );
+    }
+}
@@ -1,7 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::model::node::{NodeId, VMId};
 
+use super::prune::PruneBackups;
+
 #[derive(Deserialize, Debug)]
 pub struct ConfiguredDefaults {
     pub all: bool,             // Backup all known guest systems on this host.
@@ -45,11 +47,8 @@ pub struct ConfiguredDefaults {
     pub pool: Option<String>, // Backup all known guest systems included in the specified pool.
     #[serde(default)]
     pub protected: bool, // If true, mark backup(s) as protected.
-    #[serde(
-        rename = "prune-backups",
-        deserialize_with = "crate::deserializers::non_empty_str"
-    )]
-    pub prune_backus: Option<String>, // Use these retention options instead of those from the storage configuration.
+    #[serde(default, rename = "prune-backups")]
+    pub prune_backups: Option<PruneBackups>, // Use these retention options instead of those from the storage configuration.
     pub quiet: bool,  // Be quiet. | Spooky?
     pub remove: bool, // Prune older backups according to 'prune-backups'.
     #[serde(default, deserialize_with = "crate::deserializers::non_empty_str")]
@@ -66,7 +65,7 @@ pub struct ConfiguredDefaults {
     pub zstd: i32, // Zstd threads. N=0 uses half of the available cores, N>0 uses N as thread count.
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum Compression {
     #[serde(rename = "0")]
@@ -78,14 +77,14 @@ pub enum Compression {
     ZStd,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum MailNotification {
     Always,
     Failure,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum Mode {
     Snapshot,
@@ -93,7 +92,7 @@ pub enum Mode {
     Stop,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum NotificationPolicy {
     Always,
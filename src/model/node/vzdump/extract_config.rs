@@ -0,0 +1,168 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde_json::{Map, Value};
+
+use crate::model::node::lxc::config::LXCConfiguration;
+
+/// Guest configuration stored inside a backup archive.
+///
+/// Only container configurations are typed, VM configurations are not modeled by this crate
+/// and are returned as untyped `key: value` pairs.
+#[derive(Debug)]
+pub enum BackupConfig {
+    Container(Box<LXCConfiguration>),
+    VirtualMachine(BTreeMap<String, String>), // Untyped `key: value` pairs, with the decoded description.
+}
+
+impl FromStr for BackupConfig {
+    type Err = String;
+
+    // The configuration file format: leading '#' lines hold the description, followed by
+    // `key: value` lines. Sections like `[snapshot]` or `[PENDING]` follow the current config.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut description = Vec::new();
+        let mut entries = BTreeMap::new();
+        let mut lxc = Vec::new();
+
+        for line in s.lines().map(str::trim_start) {
+            if line.starts_with('[') {
+                break;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                description.push(decode_text(comment));
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("Invalid configuration line '{line}'"))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            if key.starts_with("lxc.") {
+                lxc.push(vec![key.to_string(), value.to_string()]);
+            } else {
+                entries.insert(key.to_string(), value.to_string());
+            }
+        }
+
+        if !description.is_empty() {
+            entries.insert("description".to_string(), description.join("\n"));
+        }
+
+        // Only containers have a root filesystem entry
+        if !entries.contains_key("rootfs") {
+            return Ok(Self::VirtualMachine(entries));
+        }
+
+        let mut config: Map<String, Value> = entries
+            .into_iter()
+            .map(|(key, value)| (key, Value::String(value)))
+            .collect();
+        config.insert("lxc".to_string(), serde_json::json!(lxc));
+
+        serde_json::from_value(Value::Object(config))
+            .map(|config| Self::Container(Box::new(config)))
+            .map_err(|err| err.to_string())
+    }
+}
+
+// Description lines are percent-encoded by Proxmox, at least '%', ':' and non-ASCII bytes
+fn decode_text(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Architecture, OSType};
+
+    use super::BackupConfig;
+
+    #[test]
+    pub fn test_container_config() {
+        let config: BackupConfig = r#"#Web server%3A 100%25 %C3%BCber-fast
+            #managed by ansible
+            arch: amd64
+            cores: 2
+            hostname: web
+            memory: 1024
+            net0: name=eth0,bridge=vmbr0,ip=dhcp,type=veth
+            onboot: 1
+            ostype: debian
+            rootfs: local-lvm:vm-100-disk-0,size=8G
+            swap: 512
+            unprivileged: 1
+            lxc.apparmor.profile: unconfined
+
+            [before-upgrade]
+            arch: amd64
+            cores: 1"#
+            .parse()
+            .unwrap();
+
+        let BackupConfig::Container(config) = config else {
+            panic!("Expected a container configuration");
+        };
+
+        assert_eq!(config.arch, Some(Architecture::Amd64));
+        assert_eq!(config.ostype, Some(OSType::Debian));
+        assert_eq!(config.cores, Some(2));
+        assert_eq!(config.memory, Some(1024));
+        assert_eq!(config.onboot, Some(true));
+        assert_eq!(config.unprivileged, Some(true));
+        assert_eq!(
+            config.description.as_deref(),
+            Some("Web server: 100% über-fast\nmanaged by ansible")
+        );
+        assert_eq!(config.lxc, vec![vec!["lxc.apparmor.profile", "unconfined"]]);
+    }
+
+    #[test]
+    pub fn test_vm_config() {
+        let config: BackupConfig =
+            "boot: order=scsi0\ncores: 4\nscsi0: local-lvm:vm-101-disk-0,size=32G\n"
+                .parse()
+                .unwrap();
+
+        let BackupConfig::VirtualMachine(config) = config else {
+            panic!("Expected a VM configuration");
+        };
+
+        assert_eq!(config.get("cores").map(String::as_str), Some("4"));
+        assert_eq!(config.len(), 3);
+    }
+
+    #[test]
+    pub fn test_malformed_container_config() {
+        let config = "arch: amd64\ncores: two\nrootfs: local-lvm:vm-100-disk-0,size=8G\n";
+
+        assert!(config.parse::<BackupConfig>().is_err());
+    }
+}
@@ -1,2 +1,4 @@
+pub mod backup;
 pub mod defaults;
 pub mod extract_config;
+pub mod prune;
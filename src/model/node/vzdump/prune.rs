@@ -0,0 +1,362 @@
+use std::{collections::BTreeMap, collections::HashSet, fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Datelike, TimeZone};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::model::node::storage::StorageContent;
+
+/// Backup retention, encoded by Proxmox as `keep-last=3,keep-daily=7,...`.
+///
+/// Setting no option at all keeps every backup, just like `keep-all=1`. Such an empty retention
+/// is left out of requests though, so the storage configuration applies instead.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(try_from = "PruneBackupsValue", into = "String")]
+pub struct PruneBackups {
+    pub keep_all: bool, // Keep all backups. Conflicts with the other options when true.
+    pub keep_last: Option<u64>, // Keep the last <N> backups.
+    pub keep_hourly: Option<u64>, // Keep backups for the last <N> different hours.
+    pub keep_daily: Option<u64>, // Keep backups for the last <N> different days.
+    pub keep_weekly: Option<u64>, // Keep backups for the last <N> different weeks.
+    pub keep_monthly: Option<u64>, // Keep backups for the last <N> different months.
+    pub keep_yearly: Option<u64>, // Keep backups for the last <N> different years.
+}
+
+// Identifies the period a backup falls into, given its time and position (newest first)
+type PeriodId = fn(&DateTime<Tz>, usize) -> String;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PruneMark {
+    Keep,
+    Remove,
+    Protected, // Protected backups are never removed and don't count towards any option.
+    Skipped,   // Backups without a usable creation time are never removed either.
+}
+
+impl PruneBackups {
+    fn options(&self) -> [(&'static str, Option<u64>); 6] {
+        [
+            ("keep-last", self.keep_last),
+            ("keep-hourly", self.keep_hourly),
+            ("keep-daily", self.keep_daily),
+            ("keep-weekly", self.keep_weekly),
+            ("keep-monthly", self.keep_monthly),
+            ("keep-yearly", self.keep_yearly),
+        ]
+    }
+
+    /// Whether no option is set at all.
+    pub fn is_empty(&self) -> bool {
+        !self.keep_all && self.options().iter().all(|(_, value)| value.is_none())
+    }
+
+    /// Checks the values are accepted by Proxmox.
+    pub fn validate(&self) -> Result<(), String> {
+        for (key, value) in self.options() {
+            if value == Some(0) {
+                return Err(format!("{key} must be at least 1"));
+            }
+            if self.keep_all && value.is_some() {
+                return Err(format!("keep-all conflicts with {key}"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Marks which backups this retention would keep or remove, newest first.
+    /// Periods are determined in `timezone`, which should be the one of the node owning the storage.
+    pub fn simulate<'a>(
+        &self,
+        backups: &'a [StorageContent],
+        timezone: &Tz,
+    ) -> Vec<(&'a StorageContent, PruneMark)> {
+        let mut backups: Vec<_> = backups.iter().collect();
+        backups.sort_by_key(|backup| std::cmp::Reverse(backup.ctime));
+
+        let times: Vec<Option<DateTime<Tz>>> = backups
+            .iter()
+            .map(|backup| {
+                backup
+                    .ctime
+                    .and_then(|ctime| timezone.timestamp_opt(ctime, 0).single())
+            })
+            .collect();
+
+        let keeps_all = self.keeps_all();
+        let mut marks: Vec<Option<PruneMark>> = backups
+            .iter()
+            .zip(&times)
+            .map(|(backup, time)| {
+                if backup.protected == Some(true) {
+                    Some(PruneMark::Protected)
+                } else if time.is_none() {
+                    Some(PruneMark::Skipped)
+                } else if keeps_all {
+                    Some(PruneMark::Keep)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let periods: [(Option<u64>, PeriodId); 6] = [
+            (self.keep_last, |_, index| index.to_string()),
+            (self.keep_hourly, |time, _| {
+                time.format("%Y/%m/%d/%H").to_string()
+            }),
+            (self.keep_daily, |time, _| {
+                time.format("%Y/%m/%d").to_string()
+            }),
+            (self.keep_weekly, |time, _| {
+                format!("{}/{}", time.iso_week().year(), time.iso_week().week())
+            }),
+            (self.keep_monthly, |time, _| {
+                format!("{}/{}", time.year(), time.month())
+            }),
+            (self.keep_yearly, |time, _| time.year().to_string()),
+        ];
+
+        for (count, period) in periods {
+            let Some(count) = count else { continue };
+
+            let already_kept: HashSet<String> = marks
+                .iter()
+                .zip(&times)
+                .enumerate()
+                .filter_map(|(index, (mark, time))| match (mark, time) {
+                    (Some(PruneMark::Keep), Some(time)) => Some(period(time, index)),
+                    _ => None,
+                })
+                .collect();
+            let mut newly_kept = HashSet::new();
+
+            for (index, (mark, time)) in marks.iter_mut().zip(&times).enumerate() {
+                let (None, Some(time)) = (&mark, time) else {
+                    continue;
+                };
+
+                let id = period(time, index);
+                if already_kept.contains(&id) {
+                    continue;
+                }
+
+                if newly_kept.contains(&id) {
+                    *mark = Some(PruneMark::Remove);
+                } else if (newly_kept.len() as u64) < count {
+                    newly_kept.insert(id);
+                    *mark = Some(PruneMark::Keep);
+                } else {
+                    break;
+                }
+            }
+        }
+
+        backups
+            .into_iter()
+            .zip(marks)
+            .map(|(backup, mark)| (backup, mark.unwrap_or(PruneMark::Remove)))
+            .collect()
+    }
+
+    fn keeps_all(&self) -> bool {
+        self.keep_all || self.options().iter().all(|(_, value)| value.is_none())
+    }
+}
+
+// Empty retentions would be sent as an empty string, skip them just like unset ones
+pub(crate) fn is_unset(value: &Option<PruneBackups>) -> bool {
+    match value {
+        Some(prune) => prune.is_empty(),
+        None => true,
+    }
+}
+
+impl FromStr for PruneBackups {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut prune = PruneBackups::default();
+
+        for part in s.split(',').filter(|x| !x.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid retention option '{part}'"))?;
+
+            if key == "keep-all" {
+                prune.keep_all = value == "1" || value == "true";
+                continue;
+            }
+
+            let count = value
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid value for {key}: '{value}'"))?;
+
+            match key {
+                "keep-last" => prune.keep_last = Some(count),
+                "keep-hourly" => prune.keep_hourly = Some(count),
+                "keep-daily" => prune.keep_daily = Some(count),
+                "keep-weekly" => prune.keep_weekly = Some(count),
+                "keep-monthly" => prune.keep_monthly = Some(count),
+                "keep-yearly" => prune.keep_yearly = Some(count),
+                _ => return Err(format!("Unknown retention option '{key}'")),
+            }
+        }
+
+        prune.validate()?;
+
+        Ok(prune)
+    }
+}
+
+impl Display for PruneBackups {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if self.keep_all {
+            parts.push("keep-all=1".to_string());
+        }
+        for (key, value) in self.options() {
+            if let Some(value) = value {
+                parts.push(format!("{key}={value}"));
+            }
+        }
+        f.write_str(&parts.join(","))
+    }
+}
+
+impl From<PruneBackups> for String {
+    fn from(value: PruneBackups) -> Self {
+        value.to_string()
+    }
+}
+
+// Some endpoints return the retention already split into an object
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PruneBackupsValue {
+    Str(String),
+    Map(BTreeMap<String, serde_json::Value>),
+}
+
+impl TryFrom<PruneBackupsValue> for PruneBackups {
+    type Error = String;
+
+    fn try_from(value: PruneBackupsValue) -> Result<Self, Self::Error> {
+        match value {
+            PruneBackupsValue::Str(s) => s.parse(),
+            PruneBackupsValue::Map(map) => map
+                .into_iter()
+                .map(|(key, value)| match value {
+                    serde_json::Value::String(value) => format!("{key}={value}"),
+                    value => format!("{key}={value}"),
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+                .parse(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{PruneBackups, PruneMark};
+    use crate::model::node::{storage::StorageContent, vzdump::backup::BackupOptions, VMId};
+
+    #[test]
+    pub fn test_parse_prune_backups() {
+        let prune: PruneBackups = "keep-last=3,keep-daily=7,keep-monthly=2".parse().unwrap();
+
+        assert_eq!(prune.keep_last, Some(3));
+        assert_eq!(prune.keep_daily, Some(7));
+        assert_eq!(prune.keep_monthly, Some(2));
+        assert_eq!(prune.to_string(), "keep-last=3,keep-daily=7,keep-monthly=2");
+
+        let prune: PruneBackups = serde_json::from_str(r#"{"keep-all": 1}"#).unwrap();
+        assert!(prune.keep_all);
+
+        assert!("keep-last=0".parse::<PruneBackups>().is_err());
+        assert!("keep-all=1,keep-last=2".parse::<PruneBackups>().is_err());
+        assert!("keep-forever=1".parse::<PruneBackups>().is_err());
+    }
+
+    #[test]
+    pub fn test_simulate_prune_backups() {
+        // Two backups per day over four days, the second oldest is protected
+        let backups: Vec<StorageContent> = (0..8)
+            .map(|i| {
+                let ctime = Utc
+                    .with_ymd_and_hms(2024, 1, 1 + i / 2, 6 + 12 * (i % 2), 0, 0)
+                    .unwrap()
+                    .timestamp();
+
+                serde_json::from_value(serde_json::json!({
+                    "volid": format!("local:backup/{i}"),
+                    "content": "backup",
+                    "format": "tar.zst",
+                    "size": 1,
+                    "ctime": ctime,
+                    "protected": i == 1,
+                }))
+                .unwrap()
+            })
+            .collect();
+
+        let prune: PruneBackups = "keep-last=1,keep-daily=2".parse().unwrap();
+        let marks: Vec<(String, PruneMark)> = prune
+            .simulate(&backups, &chrono_tz::UTC)
+            .into_iter()
+            .map(|(backup, mark)| (backup.volid.clone(), mark))
+            .collect();
+
+        // Newest first: the last backup is kept by keep-last, which already covers its day,
+        // so keep-daily keeps the newest backup of each of the two previous days
+        assert_eq!(
+            marks,
+            vec![
+                ("local:backup/7".to_string(), PruneMark::Keep),
+                ("local:backup/6".to_string(), PruneMark::Remove),
+                ("local:backup/5".to_string(), PruneMark::Keep),
+                ("local:backup/4".to_string(), PruneMark::Remove),
+                ("local:backup/3".to_string(), PruneMark::Keep),
+                ("local:backup/2".to_string(), PruneMark::Remove),
+                ("local:backup/1".to_string(), PruneMark::Protected),
+                ("local:backup/0".to_string(), PruneMark::Remove),
+            ]
+        );
+
+        let keep_all = PruneBackups::default().simulate(&backups, &chrono_tz::UTC);
+        assert!(keep_all.iter().all(|(_, mark)| *mark != PruneMark::Remove));
+
+        // Backups without a representable creation time are not put into any period
+        let mut backups = backups;
+        backups[0].ctime = None;
+        backups[2].ctime = Some(i64::MAX);
+
+        let marks = prune.simulate(&backups, &chrono_tz::UTC);
+        let mark = |volid: &str| {
+            marks
+                .iter()
+                .find(|(backup, _)| backup.volid == volid)
+                .unwrap()
+                .1
+        };
+        assert_eq!(mark("local:backup/0"), PruneMark::Skipped);
+        assert_eq!(mark("local:backup/2"), PruneMark::Skipped);
+        assert_eq!(mark("local:backup/3"), PruneMark::Keep);
+    }
+
+    #[test]
+    pub fn test_serialize_empty_prune_backups() {
+        assert!(PruneBackups::default().is_empty());
+        assert!(!"keep-all=1".parse::<PruneBackups>().unwrap().is_empty());
+
+        let options = BackupOptions {
+            prune_backups: Some(PruneBackups::default()),
+            ..BackupOptions::guests(vec![VMId::from("100")])
+        };
+        let json = serde_json::to_value(&options).unwrap();
+        assert!(json.get("prune-backups").is_none());
+    }
+}
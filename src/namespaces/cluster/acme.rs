@@ -0,0 +1,335 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::cluster::acme;
+use crate::model::PveResponse;
+
+#[derive(Clone)]
+pub struct PveClusterAcme {
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl PveClusterAcme {
+    pub(crate) fn new(host: Arc<Url>, client: Client) -> Self {
+        Self { host, client }
+    }
+
+    /// ACMEAccount index.
+    pub async fn accounts(&self) -> Result<Vec<acme::AcmeAccountName>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/account")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Return existing ACME account information.
+    pub async fn account(&self, name: &str) -> Result<acme::AcmeAccount> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/acme/account/{name}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Register a new ACME account with CA. Returns the UPID of the registration task.
+    pub async fn register_account(
+        &self,
+        registration: &acme::AccountRegistration,
+    ) -> Result<String> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/account")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(registration)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Update existing ACME account information with CA. Returns the UPID of the update task.
+    /// * `contact` - Contact email addresses, comma separated.
+    pub async fn update_account(&self, name: &str, contact: &str) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/acme/account/{name}"))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "contact": contact
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Deactivate existing ACME account at CA. Returns the UPID of the deactivation task.
+    pub async fn deactivate_account(&self, name: &str) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/acme/account/{name}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get named known ACME directory endpoints.
+    pub async fn directories(&self) -> Result<Vec<acme::AcmeDirectory>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/directories")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Retrieve ACME TermsOfService URL from CA.
+    /// * `directory` - URL of ACME CA directory endpoint.
+    pub async fn tos(&self, directory: Option<&str>) -> Result<Option<String>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/tos")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("directory", directory)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Retrieve ACME Directory Meta Information.
+    /// * `directory` - URL of ACME CA directory endpoint.
+    pub async fn meta(&self, directory: Option<&str>) -> Result<acme::AcmeMeta> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/meta")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("directory", directory)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get schema of ACME challenge types.
+    pub async fn challenge_schema(&self) -> Result<Vec<acme::ChallengeSchema>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/challenge-schema")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// ACME plugin index.
+    /// * `plugin_type` - Only list ACME plugins of a specific type
+    pub async fn plugins(
+        &self,
+        plugin_type: Option<acme::PluginType>,
+    ) -> Result<Vec<acme::AcmePlugin>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/plugins")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("type", plugin_type)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get ACME plugin configuration.
+    pub async fn plugin(&self, id: &str) -> Result<acme::AcmePlugin> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/acme/plugins/{id}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Add ACME plugin configuration.
+    /// ```no_run
+    /// # use proxmox_api::model::cluster::acme::{PluginData, PluginParameters};
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let cluster = client.cluster;
+    /// let data = PluginData::new().with("CF_Token", "...");
+    ///
+    /// cluster.acme.create_plugin(&PluginParameters::dns("cloudflare", "cf", data)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_plugin(&self, parameters: &acme::PluginParameters) -> Result<()> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/acme/plugins")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(parameters)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update ACME plugin configuration.
+    /// * `delete` - Settings to remove from the plugin.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn update_plugin(
+        &self,
+        parameters: &acme::PluginParameters,
+        delete: &[acme::PluginField],
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/cluster/acme/plugins/{}",
+                parameters.id
+            ))
+            .expect("Correct URL");
+
+        let mut body =
+            serde_json::to_value(parameters).expect("Correct serialization of PluginParameters");
+        if let Some(body) = body.as_object_mut() {
+            // The plugin type can't be changed
+            body.remove("id");
+            body.remove("type");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+            if let Some(digest) = digest {
+                body.insert("digest".into(), digest.into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete ACME plugin configuration.
+    pub async fn delete_plugin(&self, id: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/acme/plugins/{id}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
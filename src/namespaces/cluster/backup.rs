@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::cluster::backup;
+use crate::model::PveResponse;
+
+#[derive(Clone)]
+pub struct PveClusterBackup {
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl PveClusterBackup {
+    pub(crate) fn new(host: Arc<Url>, client: Client) -> Self {
+        Self { host, client }
+    }
+
+    /// List vzdump backup schedule.
+    pub async fn jobs(&self) -> Result<Vec<backup::BackupJob>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/backup")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read vzdump backup job definition.
+    pub async fn job(&self, id: &str) -> Result<backup::BackupJob> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/backup/{id}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create new vzdump backup job.
+    pub async fn create_job(&self, job: &backup::BackupJob) -> Result<()> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/backup")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(job)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update vzdump backup job definition.
+    /// * `id` - The job ID.
+    /// * `delete` - A list of settings you want to delete.
+    pub async fn update_job(
+        &self,
+        id: &str,
+        job: &backup::BackupJob,
+        delete: &[backup::BackupJobField],
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/backup/{id}"))
+            .expect("Correct URL");
+
+        let mut body = serde_json::to_value(job).expect("Correct serialization of BackupJob");
+        if let Some(body) = body.as_object_mut() {
+            body.remove("id");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete vzdump backup job definition.
+    pub async fn delete_job(&self, id: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/backup/{id}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns included guests and the backup status of their disks. Optimized to be used in ExtJS tree views.
+    pub async fn included_volumes(&self, id: &str) -> Result<Vec<backup::IncludedGuest>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/backup/{id}/included_volumes"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        let volumes: backup::IncludedVolumes = PveResponse::from_response(response).await?.data;
+
+        Ok(volumes.children)
+    }
+
+    /// Shows all guests which are not covered by any backup job.
+    pub async fn not_backed_up(&self) -> Result<Vec<backup::NotBackedUp>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/backup-info/not-backed-up")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
@@ -0,0 +1,358 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::cluster::ha;
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+#[derive(Clone)]
+pub struct PveClusterHA {
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl PveClusterHA {
+    pub(crate) fn new(host: Arc<Url>, client: Client) -> Self {
+        Self { host, client }
+    }
+
+    /// List HA resources.
+    /// * `resource_type` - Only list resources of specific type
+    pub async fn resources(
+        &self,
+        resource_type: Option<ha::HaResourceType>,
+    ) -> Result<Vec<ha::HaResource>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/ha/resources")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("type", resource_type)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read resource configuration.
+    pub async fn resource(&self, sid: &ha::HaResourceId) -> Result<ha::HaResource> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/resources/{sid}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create a new HA resource.
+    pub async fn add_resource(&self, resource: &ha::HaResource) -> Result<()> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/ha/resources")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(resource)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update resource configuration.
+    /// * `delete` - Properties to remove from the resource.
+    /// * `digest` - Prevent changes if current configuration file has a different digest.
+    pub async fn update_resource(
+        &self,
+        resource: &ha::HaResource,
+        delete: &[ha::HaResourceField],
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/resources/{}", resource.sid))
+            .expect("Correct URL");
+
+        let mut body = serde_json::to_value(resource).expect("Correct serialization of HaResource");
+        if let Some(body) = body.as_object_mut() {
+            body.remove("sid");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+            if let Some(digest) = digest {
+                body.insert("digest".into(), digest.into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete resource configuration.
+    pub async fn remove_resource(&self, sid: &ha::HaResourceId) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/resources/{sid}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Request resource migration (online) to another node.
+    pub async fn migrate(&self, sid: &ha::HaResourceId, node: &NodeId) -> Result<()> {
+        self.move_resource(sid, node, "migrate").await
+    }
+
+    /// Request resource relocation to another node. This stops the service on the old node, and restarts it on the target node.
+    pub async fn relocate(&self, sid: &ha::HaResourceId, node: &NodeId) -> Result<()> {
+        self.move_resource(sid, node, "relocate").await
+    }
+
+    /// Get HA groups.
+    pub async fn groups(&self) -> Result<Vec<ha::HaGroup>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/ha/groups")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read ha group configuration.
+    pub async fn group(&self, group: &str) -> Result<ha::HaGroup> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/groups/{group}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create a new HA group.
+    pub async fn create_group(&self, group: &ha::HaGroup) -> Result<()> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/ha/groups")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(group)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update ha group configuration.
+    /// * `delete` - Properties to remove from the group.
+    /// * `digest` - Prevent changes if current configuration file has a different digest.
+    pub async fn update_group(
+        &self,
+        group: &ha::HaGroup,
+        delete: &[ha::HaGroupField],
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/groups/{}", group.group))
+            .expect("Correct URL");
+
+        let mut body = serde_json::to_value(group).expect("Correct serialization of HaGroup");
+        if let Some(body) = body.as_object_mut() {
+            body.remove("group");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+            if let Some(digest) = digest {
+                body.insert("digest".into(), digest.into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete ha group configuration.
+    pub async fn delete_group(&self, group: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/groups/{group}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get HA manager status.
+    pub async fn status(&self) -> Result<Vec<ha::HaStatus>> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/ha/status/current")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get full HA manger status, including LRM status.
+    pub async fn manager_status(&self) -> Result<ha::ManagerStatus> {
+        let url = self
+            .host
+            .join("/api2/json/cluster/ha/status/manager_status")
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    async fn move_resource(
+        &self,
+        sid: &ha::HaResourceId,
+        node: &NodeId,
+        action: &str,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/ha/resources/{sid}/{action}"))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "node": node
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
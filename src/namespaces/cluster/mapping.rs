@@ -0,0 +1,217 @@
+use std::{fmt::Display, sync::Arc};
+
+use reqwest::{Client, StatusCode, Url};
+use serde::Serialize;
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::cluster::mapping::{PciMapping, PciMappingField, UsbMapping, UsbMappingField};
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+#[derive(Clone)]
+pub struct PveClusterMapping {
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl PveClusterMapping {
+    pub(crate) fn new(host: Arc<Url>, client: Client) -> Self {
+        Self { host, client }
+    }
+
+    /// List PCI Hardware Mapping.
+    /// * `check_node` - If given, checks the configurations on the given node for correctness, and adds relevant diagnostics for the devices to the response.
+    pub async fn pci(&self, check_node: Option<&NodeId>) -> Result<Vec<PciMapping>> {
+        self.list("pci", check_node).await
+    }
+
+    /// Get PCI Mapping.
+    pub async fn pci_mapping(&self, id: &str) -> Result<PciMapping> {
+        self.get("pci", id).await
+    }
+
+    /// Create a new hardware mapping.
+    pub async fn create_pci(&self, mapping: &PciMapping) -> Result<()> {
+        self.create("pci", mapping).await
+    }
+
+    /// Update a hardware mapping.
+    /// * `delete` - Settings to remove from the mapping.
+    /// * `digest` - Prevent changes if current configuration file has a different digest.
+    pub async fn update_pci(
+        &self,
+        mapping: &PciMapping,
+        delete: &[PciMappingField],
+        digest: Option<&str>,
+    ) -> Result<()> {
+        self.update("pci", &mapping.id, mapping, delete, digest)
+            .await
+    }
+
+    /// Remove Hardware Mapping.
+    pub async fn delete_pci(&self, id: &str) -> Result<()> {
+        self.delete("pci", id).await
+    }
+
+    /// List USB Hardware Mappings
+    /// * `check_node` - If given, checks the configurations on the given node for correctness, and adds relevant errors to the devices.
+    pub async fn usb(&self, check_node: Option<&NodeId>) -> Result<Vec<UsbMapping>> {
+        self.list("usb", check_node).await
+    }
+
+    /// Get USB Mapping.
+    pub async fn usb_mapping(&self, id: &str) -> Result<UsbMapping> {
+        self.get("usb", id).await
+    }
+
+    /// Create a new hardware mapping.
+    pub async fn create_usb(&self, mapping: &UsbMapping) -> Result<()> {
+        self.create("usb", mapping).await
+    }
+
+    /// Update a hardware mapping.
+    /// * `delete` - Settings to remove from the mapping.
+    /// * `digest` - Prevent changes if current configuration file has a different digest.
+    pub async fn update_usb(
+        &self,
+        mapping: &UsbMapping,
+        delete: &[UsbMappingField],
+        digest: Option<&str>,
+    ) -> Result<()> {
+        self.update("usb", &mapping.id, mapping, delete, digest)
+            .await
+    }
+
+    /// Remove Hardware Mapping.
+    pub async fn delete_usb(&self, id: &str) -> Result<()> {
+        self.delete("usb", id).await
+    }
+
+    async fn list<T: serde::de::DeserializeOwned>(
+        &self,
+        kind: &str,
+        check_node: Option<&NodeId>,
+    ) -> Result<Vec<T>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/mapping/{kind}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("check-node", check_node)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, kind: &str, id: &str) -> Result<T> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/mapping/{kind}/{id}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    async fn create(&self, kind: &str, mapping: &impl Serialize) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/mapping/{kind}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(mapping)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        kind: &str,
+        id: &str,
+        mapping: &impl Serialize,
+        delete: &[impl Display],
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/mapping/{kind}/{id}"))
+            .expect("Correct URL");
+
+        let mut body = serde_json::to_value(mapping).expect("Correct serialization of mapping");
+        if let Some(body) = body.as_object_mut() {
+            body.remove("id");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+            if let Some(digest) = digest {
+                body.insert("digest".into(), digest.into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, kind: &str, id: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/cluster/mapping/{kind}/{id}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
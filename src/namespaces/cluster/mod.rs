@@ -3,21 +3,39 @@ use crate::model::cluster::resources::PveClusterResource;
 use crate::model::cluster::status::PveClusterStatus;
 use crate::model::cluster::PveResourceType;
 use crate::model::PveResponse;
+use crate::namespaces::firewall::PveClusterFirewall;
 use reqwest::{Client, StatusCode, Url};
 use serde_json::Value;
 use std::sync::Arc;
 
+pub mod acme;
+pub mod backup;
+pub mod ha;
+pub mod mapping;
 pub mod sdn;
 
 #[derive(Clone)]
 pub struct PveCluster {
     host: Arc<Url>,
     client: Client,
+    pub firewall: PveClusterFirewall,
+    pub acme: acme::PveClusterAcme,
+    pub mapping: mapping::PveClusterMapping,
+    pub backup: backup::PveClusterBackup,
+    pub ha: ha::PveClusterHA,
 }
 
 impl PveCluster {
     pub fn new(host: Arc<Url>, client: Client) -> Self {
-        Self { client, host }
+        Self {
+            firewall: PveClusterFirewall::new(host.clone(), client.clone()),
+            acme: acme::PveClusterAcme::new(host.clone(), client.clone()),
+            mapping: mapping::PveClusterMapping::new(host.clone(), client.clone()),
+            backup: backup::PveClusterBackup::new(host.clone(), client.clone()),
+            ha: ha::PveClusterHA::new(host.clone(), client.clone()),
+            client,
+            host,
+        }
     }
 
     pub async fn tasks(&self) -> Result<Vec<String>> {
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::firewall::aliases::Alias;
+use crate::model::PveResponse;
+
+/// IP aliases of a guest or the cluster.
+#[derive(Clone)]
+pub struct FirewallAliases {
+    path: String,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl FirewallAliases {
+    pub(crate) fn new(path: String, host: Arc<Url>, client: Client) -> Self {
+        Self { path, host, client }
+    }
+
+    /// List aliases.
+    pub async fn list(&self) -> Result<Vec<Alias>> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read alias.
+    pub async fn get(&self, name: &str) -> Result<Alias> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create IP or Network Alias.
+    /// * `name` - Alias name.
+    /// * `cidr` - Network/IP specification in CIDR format.
+    pub async fn create(&self, name: &str, cidr: &str, comment: Option<&str>) -> Result<()> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": name,
+            "cidr": cidr,
+            "comment": comment
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update IP or Network alias.
+    /// * `name` - Alias name.
+    /// * `cidr` - Network/IP specification in CIDR format.
+    /// * `rename` - Rename an existing alias.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn update(
+        &self,
+        name: &str,
+        cidr: &str,
+        comment: Option<&str>,
+        rename: Option<&str>,
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "cidr": cidr,
+            "comment": comment,
+            "rename": rename,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove IP or Network alias.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn delete(&self, name: &str, digest: Option<&str>) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .query(&[("digest", digest)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
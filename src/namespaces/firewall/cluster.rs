@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::firewall::{groups, options, refs};
+use crate::model::PveResponse;
+
+use super::{FirewallAliases, FirewallIPSets, FirewallRules};
+
+const PATH: &str = "/api2/json/cluster/firewall";
+
+/// Datacenter wide firewall. Security groups defined here can be referenced from guest rules
+/// with [`crate::model::firewall::rules::Rule::group`].
+#[derive(Clone)]
+pub struct PveClusterFirewall {
+    host: Arc<Url>,
+    client: Client,
+    pub rules: FirewallRules,
+    pub aliases: FirewallAliases,
+    pub ipset: FirewallIPSets,
+}
+
+impl PveClusterFirewall {
+    pub(crate) fn new(host: Arc<Url>, client: Client) -> Self {
+        Self {
+            rules: FirewallRules::new(format!("{PATH}/rules"), host.clone(), client.clone()),
+            aliases: FirewallAliases::new(format!("{PATH}/aliases"), host.clone(), client.clone()),
+            ipset: FirewallIPSets::new(format!("{PATH}/ipset"), host.clone(), client.clone()),
+            host,
+            client,
+        }
+    }
+
+    /// List security groups.
+    pub async fn groups(&self) -> Result<Vec<groups::SecurityGroup>> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/groups"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Rules of a security group.
+    pub fn group(&self, name: &str) -> FirewallRules {
+        FirewallRules::new(
+            format!("{PATH}/groups/{name}"),
+            self.host.clone(),
+            self.client.clone(),
+        )
+    }
+
+    /// Create new security group.
+    /// * `name` - Security Group name.
+    pub async fn create_group(&self, name: &str, comment: Option<&str>) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/groups"))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "group": name,
+            "comment": comment
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rename an existing security group.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn rename_group(
+        &self,
+        name: &str,
+        new_name: &str,
+        comment: Option<&str>,
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/groups"))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "group": new_name,
+            "rename": name,
+            "comment": comment,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete security group. The group has to be empty.
+    pub async fn delete_group(&self, name: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/groups/{name}"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// List available macros.
+    pub async fn macros(&self) -> Result<Vec<groups::Macro>> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/macros"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get Firewall options.
+    pub async fn options(&self) -> Result<options::ClusterOptions> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/options"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Set Firewall options.
+    /// * `options` - Options to set, unset fields are left untouched.
+    /// * `delete` - Settings to reset to their default.
+    pub async fn update_options(
+        &self,
+        options: &options::ClusterOptions,
+        delete: &[options::ClusterOptionsField],
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/options"))
+            .expect("Correct URL");
+
+        let mut body =
+            serde_json::to_value(options).expect("Correct serialization of ClusterOptions");
+        if let (Some(body), false) = (body.as_object_mut(), delete.is_empty()) {
+            let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+            body.insert("delete".into(), delete.join(",").into());
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lists possible IPSet/Alias reference which are allowed in source/dest properties.
+    /// * `ref_type` - Only list references of specified type.
+    pub async fn refs(&self, ref_type: Option<refs::RefType>) -> Result<Vec<refs::FirewallRef>> {
+        let url = self
+            .host
+            .join(&format!("{PATH}/refs"))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("type", ref_type)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::firewall::{log, options, refs};
+use crate::model::PveResponse;
+
+use super::{FirewallAliases, FirewallIPSets, FirewallRules};
+
+/// Firewall of a single guest.
+#[derive(Clone)]
+pub struct PveGuestFirewall {
+    path: String,
+    host: Arc<Url>,
+    client: Client,
+    pub rules: FirewallRules,
+    pub aliases: FirewallAliases,
+    pub ipset: FirewallIPSets,
+}
+
+impl PveGuestFirewall {
+    /// * `path` - API path of the guest firewall, e.g. `/api2/json/nodes/{node}/lxc/{vmid}/firewall`.
+    pub(crate) fn new(path: String, host: Arc<Url>, client: Client) -> Self {
+        Self {
+            rules: FirewallRules::new(format!("{path}/rules"), host.clone(), client.clone()),
+            aliases: FirewallAliases::new(format!("{path}/aliases"), host.clone(), client.clone()),
+            ipset: FirewallIPSets::new(format!("{path}/ipset"), host.clone(), client.clone()),
+            path,
+            host,
+            client,
+        }
+    }
+
+    /// Get VM firewall options.
+    pub async fn options(&self) -> Result<options::GuestOptions> {
+        let url = self
+            .host
+            .join(&format!("{}/options", self.path))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Set Firewall options.
+    /// * `options` - Options to set, unset fields are left untouched.
+    /// * `delete` - Settings to reset to their default.
+    pub async fn update_options(
+        &self,
+        options: &options::GuestOptions,
+        delete: &[options::GuestOptionsField],
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/options", self.path))
+            .expect("Correct URL");
+
+        let mut body =
+            serde_json::to_value(options).expect("Correct serialization of GuestOptions");
+        if let (Some(body), false) = (body.as_object_mut(), delete.is_empty()) {
+            let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+            body.insert("delete".into(), delete.join(",").into());
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lists possible IPSet/Alias reference which are allowed in source/dest properties.
+    /// * `ref_type` - Only list references of specified type.
+    pub async fn refs(&self, ref_type: Option<refs::RefType>) -> Result<Vec<refs::FirewallRef>> {
+        let url = self
+            .host
+            .join(&format!("{}/refs", self.path))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("type", ref_type)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read firewall log.
+    pub async fn log(&self, filter: &log::LogFilter) -> Result<Vec<log::LogEntry>> {
+        let url = self
+            .host
+            .join(&format!("{}/log", self.path))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(filter)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::firewall::ipset::{IPSet, IPSetEntry};
+use crate::model::PveResponse;
+
+/// IP sets of a guest or the cluster.
+#[derive(Clone)]
+pub struct FirewallIPSets {
+    path: String,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl FirewallIPSets {
+    pub(crate) fn new(path: String, host: Arc<Url>, client: Client) -> Self {
+        Self { path, host, client }
+    }
+
+    // CIDRs contain a '/', so they have to be pushed as an encoded path segment.
+    fn entry_url(&self, name: &str, cidr: &str) -> Url {
+        let mut url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        url.path_segments_mut().expect("Correct URL").push(cidr);
+
+        url
+    }
+
+    /// List IPSets.
+    pub async fn list(&self) -> Result<Vec<IPSet>> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create new IPSet.
+    /// * `name` - IP set name.
+    pub async fn create(&self, name: &str, comment: Option<&str>) -> Result<()> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": name,
+            "comment": comment
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rename an existing IPSet.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn rename(
+        &self,
+        name: &str,
+        new_name: &str,
+        comment: Option<&str>,
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": new_name,
+            "rename": name,
+            "comment": comment,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete IPSet.
+    /// * `force` - Delete all members of the IPSet, if there are any.
+    pub async fn delete(&self, name: &str, force: bool) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .query(&[("force", force)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// List IPSet content.
+    pub async fn entries(&self, name: &str) -> Result<Vec<IPSetEntry>> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read IP or Network settings from IPSet.
+    pub async fn entry(&self, name: &str, cidr: &str) -> Result<IPSetEntry> {
+        let url = self.entry_url(name, cidr);
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Add IP or Network to IPSet.
+    /// * `cidr` - Network/IP specification in CIDR format.
+    /// * `nomatch` - Exclude this entry from the set.
+    pub async fn add(
+        &self,
+        name: &str,
+        cidr: &str,
+        comment: Option<&str>,
+        nomatch: bool,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, name))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "cidr": cidr,
+            "comment": comment,
+            "nomatch": nomatch
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update IP or Network settings.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn update(
+        &self,
+        name: &str,
+        cidr: &str,
+        comment: Option<&str>,
+        nomatch: bool,
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self.entry_url(name, cidr);
+
+        let body = serde_json::json!({
+            "comment": comment,
+            "nomatch": nomatch,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove IP or Network from IPSet.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn remove(&self, name: &str, cidr: &str, digest: Option<&str>) -> Result<()> {
+        let url = self.entry_url(name, cidr);
+
+        let response = self
+            .client
+            .delete(url)
+            .query(&[("digest", digest)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
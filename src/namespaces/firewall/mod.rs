@@ -0,0 +1,11 @@
+mod aliases;
+mod cluster;
+mod guest;
+mod ipset;
+mod rules;
+
+pub use aliases::FirewallAliases;
+pub use cluster::PveClusterFirewall;
+pub use guest::PveGuestFirewall;
+pub use ipset::FirewallIPSets;
+pub use rules::FirewallRules;
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::error::{ProxmoxAPIError, Result};
+use crate::model::firewall::rules::{Rule, RuleField};
+use crate::model::PveResponse;
+
+/// Rules of a guest, the cluster or a security group.
+#[derive(Clone)]
+pub struct FirewallRules {
+    path: String,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl FirewallRules {
+    pub(crate) fn new(path: String, host: Arc<Url>, client: Client) -> Self {
+        Self { path, host, client }
+    }
+
+    /// List rules.
+    pub async fn list(&self) -> Result<Vec<Rule>> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get single rule data.
+    pub async fn get(&self, pos: u64) -> Result<Rule> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, pos))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create new rule. The rule is inserted at `rule.pos`, or at the top if unset.
+    pub async fn create(&self, rule: &Rule) -> Result<()> {
+        let url = self.host.join(&self.path).expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(rule)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Modify rule data.
+    /// * `pos` - Position of the rule to modify.
+    /// * `rule` - New rule data. `rule.pos` is ignored, use [`FirewallRules::move_to`] to move rules.
+    /// * `delete` - Properties to remove from the rule.
+    pub async fn update(&self, pos: u64, rule: &Rule, delete: &[RuleField]) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, pos))
+            .expect("Correct URL");
+
+        let mut body = serde_json::to_value(rule).expect("Correct serialization of Rule");
+        if let Some(body) = body.as_object_mut() {
+            body.remove("pos");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Move rule to another position.
+    /// * `pos` - Current position of the rule.
+    /// * `moveto` - New position of the rule. Following rules are shifted down.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn move_to(&self, pos: u64, moveto: u64, digest: Option<&str>) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, pos))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "moveto": moveto,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete rule.
+    /// * `digest` - Prevent changes if current configuration file has a different digest. This can be used to prevent concurrent modifications.
+    pub async fn delete(&self, pos: u64, digest: Option<&str>) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("{}/{}", self.path, pos))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .query(&[("digest", digest)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
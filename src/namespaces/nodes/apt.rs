@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::model::node::apt;
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Apt {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Apt {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// This is used to resynchronize the package index files from their sources (apt-get update).
+    /// Returns the UPID of the update task.
+    /// * `notify` - Send notification about new packages.
+    /// * `quiet` - Only produces output suitable for logging, omitting progress indicators.
+    pub async fn update_database(&self, notify: bool, quiet: bool) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/update", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "notify": notify,
+            "quiet": quiet
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List available updates.
+    pub async fn updates(&self) -> Result<Vec<apt::Package>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/update", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get package information for important Proxmox packages.
+    pub async fn versions(&self) -> Result<Vec<apt::Package>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/versions", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get package changelogs.
+    /// * `name` - Package name.
+    /// * `version` - Package version.
+    pub async fn changelog(&self, name: &str, version: Option<&str>) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/changelog", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("name", Some(name)), ("version", version)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get APT repository information.
+    pub async fn repositories(&self) -> Result<apt::RepositoryConfiguration> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/repositories", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Change the enabled property of a repository.
+    /// * `path` - Path to the containing file.
+    /// * `index` - Index within the file (starting from 0).
+    /// * `enabled` - Whether the repository should be enabled or not.
+    /// * `digest` - Digest to detect modifications.
+    pub async fn set_repository_enabled(
+        &self,
+        path: &str,
+        index: u64,
+        enabled: bool,
+        digest: Option<&str>,
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/repositories", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "path": path,
+            "index": index,
+            "enabled": enabled,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add a standard repository to the configuration.
+    /// * `handle` - Handle that identifies a repository.
+    /// * `digest` - Digest to detect modifications.
+    ///
+    /// [`apt::StandardRepositoryHandle::Other`] only stands for handles unknown to this crate and
+    /// is rejected.
+    pub async fn add_standard_repository(
+        &self,
+        handle: apt::StandardRepositoryHandle,
+        digest: Option<&str>,
+    ) -> Result<()> {
+        if handle == apt::StandardRepositoryHandle::Other {
+            return Err(ProxmoxAPIError::InvalidParameter(
+                "Unknown standard repository handle".into(),
+            ));
+        }
+
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/apt/repositories", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "handle": handle,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
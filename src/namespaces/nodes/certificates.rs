@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::model::node::certificates::CertificateInfo;
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Certificates {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Certificates {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// Get information about node's certificates.
+    pub async fn info(&self) -> Result<Vec<CertificateInfo>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/certificates/info", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Upload or update custom certificate chain and key.
+    /// * `certificates` - PEM encoded certificate (chain).
+    /// * `key` - PEM encoded private key.
+    /// * `force` - Overwrite existing custom or ACME certificate files.
+    /// * `restart` - Restart pveproxy.
+    pub async fn upload_custom(
+        &self,
+        certificates: &str,
+        key: Option<&str>,
+        force: bool,
+        restart: bool,
+    ) -> Result<CertificateInfo> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/certificates/custom", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "certificates": certificates,
+            "key": key,
+            "force": force,
+            "restart": restart
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// DELETE custom certificate chain and key.
+    /// * `restart` - Restart pveproxy.
+    pub async fn remove_custom(&self, restart: bool) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/certificates/custom", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .query(&[("restart", restart)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Order a new certificate from ACME-compatible CA. Returns the UPID of the order task.
+    /// * `force` - Overwrite existing custom certificate.
+    pub async fn order_acme(&self, force: bool) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/certificates/acme/certificate",
+                self.id
+            ))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "force": force
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Renew existing certificate from CA. Returns the UPID of the renew task.
+    /// * `force` - Force renewal even if expiry is more than 30 days away.
+    pub async fn renew_acme(&self, force: bool) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/certificates/acme/certificate",
+                self.id
+            ))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "force": force
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Revoke existing certificate from CA. Returns the UPID of the revoke task.
+    pub async fn revoke_acme(&self) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/certificates/acme/certificate",
+                self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
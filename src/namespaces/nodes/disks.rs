@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use reqwest::{Client, Url};
+
+use crate::model::node::disks;
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Disks {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Disks {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// List local disks.
+    /// * `include_partitions` - Also include partitions.
+    /// * `skip_smart` - Skip smart checks.
+    /// * `type_filter` - Only list specific types of disks.
+    pub async fn list(
+        &self,
+        include_partitions: bool,
+        skip_smart: bool,
+        type_filter: Option<disks::DiskTypeFilter>,
+    ) -> Result<Vec<disks::Disk>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/list", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[
+                ("include-partitions", include_partitions),
+                ("skipsmart", skip_smart),
+            ])
+            .query(&[("type", type_filter)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get SMART Health of a disk.
+    /// * `disk` - Block device name, e.g. `/dev/sda`.
+    /// * `health_only` - If true returns only the health status.
+    pub async fn smart(&self, disk: &str, health_only: bool) -> Result<disks::SmartData> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/smart", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("disk", disk)])
+            .query(&[("healthonly", health_only)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Initialize Disk with GPT. Returns the UPID of the task.
+    /// * `disk` - Block device name, e.g. `/dev/sdb`.
+    /// * `uuid` - UUID for the GPT table.
+    pub async fn init_gpt(&self, disk: &str, uuid: Option<&str>) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/initgpt", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "disk": disk,
+            "uuid": uuid
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Wipe a disk or partition. Returns the UPID of the task.
+    /// * `disk` - Block device name, e.g. `/dev/sdb`.
+    pub async fn wipe(&self, disk: &str) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/wipedisk", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "disk": disk
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List Zpools.
+    pub async fn zfs(&self) -> Result<Vec<disks::ZfsPool>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/zfs", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create a ZFS pool. Returns the UPID of the task.
+    /// * `name` - The storage identifier.
+    /// * `devices` - The block devices you want to create the zpool on.
+    /// * `raid_level` - The RAID level to use.
+    /// * `compression` - The compression algorithm to use.
+    /// * `ashift` - Pool sector size exponent.
+    /// * `add_storage` - Configure storage using the zpool.
+    pub async fn create_zfs(
+        &self,
+        name: &str,
+        devices: &[&str],
+        raid_level: disks::ZfsRaidLevel,
+        compression: Option<disks::ZfsCompression>,
+        ashift: Option<u8>,
+        add_storage: bool,
+    ) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/zfs", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": name,
+            "devices": devices.join(","),
+            "raidlevel": raid_level,
+            "compression": compression,
+            "ashift": ashift,
+            "add_storage": add_storage
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List LVM Volume Groups.
+    pub async fn lvm(&self) -> Result<disks::LvmVolumeGroups> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/lvm", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create an LVM Volume Group. Returns the UPID of the task.
+    /// * `name` - The storage identifier.
+    /// * `device` - The block device you want to create the volume group on.
+    /// * `add_storage` - Configure storage using the Volume Group.
+    pub async fn create_lvm(&self, name: &str, device: &str, add_storage: bool) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/lvm", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": name,
+            "device": device,
+            "add_storage": add_storage
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List LVM thinpools.
+    pub async fn lvm_thin(&self) -> Result<Vec<disks::LvmThinPool>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/lvmthin", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create an LVM thinpool. Returns the UPID of the task.
+    /// * `name` - The storage identifier.
+    /// * `device` - The block device you want to create the thinpool on.
+    /// * `add_storage` - Configure storage using the thinpool.
+    pub async fn create_lvm_thin(
+        &self,
+        name: &str,
+        device: &str,
+        add_storage: bool,
+    ) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/lvmthin", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": name,
+            "device": device,
+            "add_storage": add_storage
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// PVE Managed Directory storages.
+    pub async fn directories(&self) -> Result<Vec<disks::DirectoryMount>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/directory", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create a Filesystem on an unused disk. Will be mounted under '/mnt/pve/NAME'. Returns the UPID of the task.
+    /// * `name` - The storage identifier.
+    /// * `device` - The block device you want to create the filesystem on.
+    /// * `filesystem` - The desired filesystem, defaults to ext4.
+    /// * `add_storage` - Configure storage using the directory.
+    pub async fn create_directory(
+        &self,
+        name: &str,
+        device: &str,
+        filesystem: Option<disks::Filesystem>,
+        add_storage: bool,
+    ) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/disks/directory", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "name": name,
+            "device": device,
+            "filesystem": filesystem,
+            "add_storage": add_storage
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
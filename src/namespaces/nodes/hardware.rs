@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use reqwest::{Client, Url};
+
+use crate::model::node::hardware::{MediatedDeviceType, PciDevice, PciFilter, UsbDevice};
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Hardware {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Hardware {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// List local PCI devices.
+    pub async fn pci(&self, filter: &PciFilter) -> Result<Vec<PciDevice>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/hardware/pci", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(filter)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List mediated device types for the given PCI device.
+    /// * `pci_id` - The PCI ID or mapping to list the mdev types for.
+    pub async fn mdev_types(&self, pci_id: &str) -> Result<Vec<MediatedDeviceType>> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/hardware/pci/{}/mdev",
+                self.id, pci_id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List local USB devices.
+    pub async fn usb(&self) -> Result<Vec<UsbDevice>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/hardware/usb", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
@@ -6,6 +6,7 @@ use reqwest::{Client, StatusCode, Url};
 use crate::error::{ProxmoxAPIError, Result};
 use crate::model::node::{NodeId, VMId};
 use crate::model::{self, PveResponse, Size};
+use crate::namespaces::firewall::PveGuestFirewall;
 
 mod status;
 
@@ -16,6 +17,7 @@ pub struct PveLXC {
     host: Arc<Url>,
     client: Client,
     pub status: status::PveLXCStatus,
+    pub firewall: PveGuestFirewall,
 }
 
 impl PveLXC {
@@ -31,6 +33,11 @@ impl PveLXC {
                 host.clone(),
                 client.clone(),
             ),
+            firewall: PveGuestFirewall::new(
+                format!("/api2/json/nodes/{}/lxc/{}/firewall", node_id, id),
+                host.clone(),
+                client.clone(),
+            ),
         }
     }
 
@@ -74,9 +81,14 @@ impl PveLXC {
     /// * `additive` - Add the size to the actual size
     /// * `disk` - The disk you want to resize.
     /// * `digest` - Prevent changes if current configuration file has different SHA1 digest. This can be used to prevent concurrent modifications.
-    /// ```
+    /// ```no_run
+    /// # use proxmox_api::model::Size;
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let lxc = client.node("pve1").lxc("100");
     /// // Adds 256 GB
-    /// lxc.resize(Size::GB(256.0), true, "rootfs", None);
+    /// lxc.resize(Size::GB(256.0), true, "rootfs", None).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn resize(
         &self,
@@ -98,7 +110,7 @@ impl PveLXC {
         let size = if !additive {
             size.to_string()
         } else {
-            format!("+{}", size.to_string())
+            format!("+{size}")
         };
 
         let body = serde_json::json!({
@@ -134,8 +146,12 @@ impl PveLXC {
     /// * `timeout` - Timeout in seconds for shutdown for restart migration
     ///
     /// Example:
-    /// ```
-    /// lxc.migrate("pve01", None, true, false, None, None)
+    /// ```no_run
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let lxc = client.node("pve1").lxc("100");
+    /// lxc.migrate("pve01".into(), None, true, false, None, None).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn migrate(
         &self,
@@ -204,8 +220,13 @@ impl PveLXC {
     /// Get container configuration.
     /// * `current` - Get current values (instead of pending values).
     /// * `snapshot` - Fetch config values from given snapshot.
+    /// ```no_run
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let lxc = client.node("pve1").lxc("100");
+    /// lxc.config(true, None).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    /// lxc.config(true, None);
     pub async fn config(
         &self,
         current: bool,
@@ -219,14 +240,65 @@ impl PveLXC {
             ))
             .expect("Correct URL");
 
+        let response = self
+            .client
+            .get(url)
+            .query(&[("current", current)])
+            .query(&[("snapshot", snapshot)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Creates a TCP proxy connection.
+    pub async fn termproxy(&self) -> Result<model::node::console::TermProxy> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/lxc/{}/termproxy",
+                self.node_id, self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Creates a TCP VNC proxy connections.
+    /// * `websocket` - Use websocket instead of standard VNC.
+    /// * `width` - Sets the width of the console in pixels.
+    /// * `height` - Sets the height of the console in pixels.
+    pub async fn vncproxy(
+        &self,
+        websocket: bool,
+        width: Option<u16>,
+        height: Option<u16>,
+    ) -> Result<model::node::console::VncProxy> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/lxc/{}/vncproxy",
+                self.node_id, self.id
+            ))
+            .expect("Correct URL");
+
         let body = serde_json::json!({
-            "current": current,
-            "snapshot": snapshot
+            "websocket": websocket,
+            "width": width,
+            "height": height
         });
 
         let response = self
             .client
-            .get(url)
+            .post(url)
             .json(&body)
             .send()
             .await
@@ -235,17 +307,89 @@ impl PveLXC {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
-    /// Create a container clone/copy
+    /// Opens a websocket to a termproxy created with [`PveLXC::termproxy`].
+    /// ```no_run
+    /// # use tokio::io::AsyncWriteExt;
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let lxc = client.node("pve1").lxc("100");
+    /// let proxy = lxc.termproxy().await?;
+    /// let mut console = lxc.vnc_websocket(&proxy).await?;
+    ///
+    /// console.resize(80, 24).await?;
+    /// console.write_all(b"uname -a\n").await?;
+    /// console.flush().await?;
+    /// # Ok(())
+    /// # }
     /// ```
+    #[cfg(feature = "websocket")]
+    pub async fn vnc_websocket(
+        &self,
+        proxy: &model::node::console::TermProxy,
+    ) -> Result<crate::xterm::XTermStream> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/lxc/{}/vncwebsocket",
+                self.node_id, self.id
+            ))
+            .expect("Correct URL");
+
+        crate::xterm::XTermStream::connect(&self.client, url, proxy).await
+    }
+
+    /// Check if feature for virtual machine is available.
+    /// * `feature` - Feature to check.
+    /// * `snapname` - The name of the snapshot.
+    pub async fn feature(
+        &self,
+        feature: model::node::lxc::feature::Feature,
+        snapname: Option<&str>,
+    ) -> Result<model::node::lxc::feature::FeatureAvailability> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/lxc/{}/feature",
+                self.node_id, self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("feature", feature)])
+            .query(&[("snapname", snapname)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create a container clone/copy
+    ///
+    /// With `check_feature` set, the container is checked to be a template when a linked clone
+    /// is requested and the storage is asked whether it can clone/copy before creating the task.
+    /// ```no_run
+    /// # use proxmox_api::model::node::{lxc::clone::Parameters, VMId};
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let lxc = client.node("pve1").lxc("100");
     /// lxc.clone(Parameters {
-    ///     new_id: VMId("200".into()),
+    ///     new_id: VMId::from("200"),
+    ///     check_feature: true,
     ///     ..Default::default()
     /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn clone<'a>(
         &self,
         parameters: model::node::lxc::clone::Parameters<'a>,
     ) -> Result<()> {
+        if parameters.check_feature {
+            self.check_clone(&parameters).await?;
+        }
+
         let url = self
             .host
             .join(&format!(
@@ -283,4 +427,99 @@ impl PveLXC {
 
         Ok(())
     }
+
+    /// Restore this container from a vzdump backup archive. Returns the UPID of the restore task.
+    /// * `archive` - Volume identifier of the backup, e.g. `local:backup/vzdump-lxc-100-2024_01_01-00_00_00.tar.zst`.
+    pub async fn restore(
+        &self,
+        archive: &str,
+        parameters: &model::node::lxc::restore::RestoreParameters,
+    ) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/lxc", self.node_id))
+            .expect("Correct URL");
+
+        let mut body =
+            serde_json::to_value(parameters).expect("Correct serialization of RestoreParameters");
+        if let Some(body) = body.as_object_mut() {
+            body.insert("vmid".into(), self.id.to_string().into());
+            body.insert("ostemplate".into(), archive.into());
+            body.insert("restore".into(), true.into());
+        }
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Restore this container from the latest backup of `source` on `backup_storage`.
+    /// Returns the UPID of the restore task.
+    ///
+    /// ```no_run
+    /// # use proxmox_api::model::node::{lxc::restore::RestoreParameters, VMId};
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let node = client.node("pve1");
+    /// let upid = node
+    ///     .lxc("100")
+    ///     .restore_latest("pbs", &VMId::from("100"), &RestoreParameters {
+    ///         force: Some(true),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore_latest(
+        &self,
+        backup_storage: &str,
+        source: &VMId,
+        parameters: &model::node::lxc::restore::RestoreParameters,
+    ) -> Result<String> {
+        let storage = crate::namespaces::nodes::storage::PveStorage::new(
+            self.node_id.clone(),
+            backup_storage.to_string(),
+            self.host.clone(),
+            self.client.clone(),
+        );
+
+        let backup = storage
+            .latest_backup(source)
+            .await?
+            .ok_or_else(|| ProxmoxAPIError::NoBackup(source.clone()))?;
+
+        self.restore(&backup.volid, parameters).await
+    }
+
+    async fn check_clone(
+        &self,
+        parameters: &model::node::lxc::clone::Parameters<'_>,
+    ) -> Result<()> {
+        use model::node::lxc::feature::Feature;
+
+        let config = self.config(true, parameters.snapname).await?;
+        let template = config.template.unwrap_or(false);
+
+        let feature = Feature::for_clone(template, parameters.full)
+            .ok_or_else(|| ProxmoxAPIError::NotATemplate(self.id.clone()))?;
+
+        if !self
+            .feature(feature, parameters.snapname)
+            .await?
+            .has_feature
+        {
+            return Err(ProxmoxAPIError::FeatureUnavailable(
+                self.id.clone(),
+                feature,
+            ));
+        }
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use std::{sync::Arc, time::Duration};
+use std::{net::IpAddr, sync::Arc, time::Duration};
 
 use reqwest::{Client, StatusCode, Url};
 
@@ -12,8 +12,18 @@ use crate::{
 };
 
 use self::lxc::PveLXC;
+use self::storage::PveStorage;
 
+mod apt;
+mod certificates;
+mod disks;
+mod hardware;
 mod lxc;
+mod network;
+mod scan;
+mod services;
+mod storage;
+mod subscription;
 mod tasks;
 mod vzdump;
 
@@ -24,6 +34,14 @@ pub struct PveNode {
     client: Client,
     pub vzdump: vzdump::VZDump,
     pub tasks: tasks::Tasks,
+    pub network: network::Network,
+    pub certificates: certificates::Certificates,
+    pub disks: disks::Disks,
+    pub apt: apt::Apt,
+    pub services: services::Services,
+    pub subscription: subscription::Subscription,
+    pub scan: scan::Scan,
+    pub hardware: hardware::Hardware,
 }
 
 impl PveNode {
@@ -34,6 +52,22 @@ impl PveNode {
             client: client.clone(),
             vzdump: vzdump::VZDump::new(id.clone(), host.clone(), client.clone()),
             tasks: tasks::Tasks::new(id.clone(), host.clone(), client.clone()),
+            network: network::Network::new(id.clone(), host.clone(), client.clone()),
+            certificates: certificates::Certificates::new(
+                id.clone(),
+                host.clone(),
+                client.clone(),
+            ),
+            disks: disks::Disks::new(id.clone(), host.clone(), client.clone()),
+            apt: apt::Apt::new(id.clone(), host.clone(), client.clone()),
+            services: services::Services::new(id.clone(), host.clone(), client.clone()),
+            subscription: subscription::Subscription::new(
+                id.clone(),
+                host.clone(),
+                client.clone(),
+            ),
+            scan: scan::Scan::new(id.clone(), host.clone(), client.clone()),
+            hardware: hardware::Hardware::new(id.clone(), host.clone(), client.clone()),
         }
     }
 
@@ -98,6 +132,36 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Set time zone.
+    /// * `timezone` - Time zone, e.g. `chrono_tz::Europe::Vienna`.
+    pub async fn set_timezone(&self, timezone: chrono_tz::Tz) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/time", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "timezone": timezone.name()
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Suspend all VMs.
     pub async fn suspend_all(&self) -> Result<()> {
         let url = self
@@ -227,6 +291,102 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Read node status
+    pub async fn status(&self) -> Result<model::node::status::NodeStatus> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/status", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Reboot or shutdown a node.
+    pub async fn power(&self, command: model::node::status::PowerCommand) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/status", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "command": command
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reboot the node.
+    pub async fn reboot(&self) -> Result<()> {
+        self.power(model::node::status::PowerCommand::Reboot).await
+    }
+
+    /// Shut the node down.
+    pub async fn shutdown(&self) -> Result<()> {
+        self.power(model::node::status::PowerCommand::Shutdown).await
+    }
+
+    /// List all custom and default CPU models.
+    pub async fn cpu_models(&self) -> Result<Vec<model::node::capabilities::CpuModel>> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/capabilities/qemu/cpu",
+                self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Get available QEMU/KVM machine types.
+    pub async fn machine_types(&self) -> Result<Vec<model::node::capabilities::MachineType>> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/capabilities/qemu/machines",
+                self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
     /// Read tap/vm network device interface counters
     pub async fn netstat(&self) -> Result<model::node::netstat::NetStat> {
         let url = self
@@ -244,6 +404,112 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Read system log
+    pub async fn syslog(
+        &self,
+        filter: &model::node::logs::SyslogFilter,
+    ) -> Result<Vec<model::node::logs::LogEntry>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/syslog", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(filter)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read Journal
+    pub async fn journal(
+        &self,
+        filter: &model::node::logs::JournalFilter,
+    ) -> Result<model::node::logs::JournalPage> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/journal", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(filter)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        let lines: Vec<String> = PveResponse::from_response(response).await?.data;
+
+        Ok(lines.into())
+    }
+
+    /// Follows the journal, polling for new entries after the last seen cursor.
+    /// Errors are yielded and polling continues after the next `interval`.
+    /// * `last_entries` - Number of entries to start with.
+    /// * `interval` - Time to wait between polls.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use futures_util::StreamExt;
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let node = client.node("pve1");
+    /// let mut journal = std::pin::pin!(node.follow_journal(Some(50), Duration::from_secs(5)));
+    ///
+    /// while let Some(line) = journal.next().await {
+    ///     println!("{}", line?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn follow_journal(
+        &self,
+        last_entries: Option<u64>,
+        interval: Duration,
+    ) -> impl futures_util::Stream<Item = Result<String>> {
+        let state = (
+            self.clone(),
+            None::<String>,
+            std::collections::VecDeque::new(),
+            true,
+        );
+
+        futures_util::stream::unfold(
+            state,
+            move |(node, mut cursor, mut pending, mut first)| async move {
+                loop {
+                    if let Some(line) = pending.pop_front() {
+                        return Some((Ok(line), (node, cursor, pending, first)));
+                    }
+
+                    if !first {
+                        tokio::time::sleep(interval).await;
+                    }
+                    first = false;
+
+                    let filter = model::node::logs::JournalFilter {
+                        last_entries: cursor.is_none().then_some(last_entries).flatten(),
+                        start_cursor: cursor.clone(),
+                        ..Default::default()
+                    };
+
+                    match node.journal(&filter).await {
+                        Ok(page) => {
+                            cursor = page.end_cursor.or(cursor);
+                            pending.extend(page.lines);
+                        }
+                        Err(err) => return Some((Err(err), (node, cursor, pending, first))),
+                    }
+                }
+            },
+        )
+    }
+
     /// Migrate all VMs and Containers.
     pub async fn migrate_all(
         &self,
@@ -297,6 +563,38 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Write /etc/hosts.
+    /// * `data` - The target content of /etc/hosts.
+    /// * `digest` - Digest returned by [`PveNode::hosts`]. Prevents overwriting concurrent modifications.
+    pub async fn update_hosts(&self, data: &str, digest: Option<&str>) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/hosts", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "data": data,
+            "digest": digest
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Execute multiple commands in order, root only.
     pub async fn execute(&self, commands: &[model::node::execute::Command]) -> Result<()> {
         let url = self
@@ -346,6 +644,46 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Write DNS settings.
+    /// * `search` - Search domain for host-name lookup.
+    /// * `servers` - Up to three name server IP addresses, in order of preference.
+    pub async fn update_dns(&self, search: &str, servers: &[IpAddr]) -> Result<()> {
+        if servers.len() > 3 {
+            return Err(ProxmoxAPIError::InvalidParameter(
+                "At most three name servers are supported".into(),
+            ));
+        }
+
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/dns", self.id))
+            .expect("Correct URL");
+
+        let mut body = serde_json::json!({
+            "search": search
+        });
+        for (i, server) in servers.iter().enumerate() {
+            body[format!("dns{}", i + 1)] = server.to_string().into();
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get node configuration options.
     pub async fn config(
         &self,
@@ -371,6 +709,55 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Set node configuration options.
+    /// * `config` - Options to set, unset fields are left untouched. `config.digest` guards against concurrent modifications.
+    /// * `delete` - A list of settings you want to delete.
+    /// ```no_run
+    /// # use proxmox_api::model::node::config::{AcmeDomain, Property};
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let node = client.node("pve1");
+    /// let mut config = node.config(None).await?;
+    /// config.acmedomain0 = Some(AcmeDomain::new("pve1.example.com"));
+    ///
+    /// node.update_config(&config, &[Property::WakeOnLan]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_config(
+        &self,
+        config: &model::node::config::NodeConfiguration,
+        delete: &[model::node::config::Property],
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/config", self.id))
+            .expect("Correct URL");
+
+        let mut body =
+            serde_json::to_value(config).expect("Correct serialization of NodeConfiguration");
+        if let (Some(body), false) = (body.as_object_mut(), delete.is_empty()) {
+            let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+            body.insert("delete".into(), delete.join(",").into());
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get list of appliances.
     pub async fn apl_info(&self) -> Result<Vec<model::node::aplinfo::ApplianceInformation>> {
         let url = self
@@ -391,13 +778,18 @@ impl PveNode {
     /// Read task list for one node (finished tasks).
     ///
     /// You can apply a filter like this:
-    /// ```
+    /// ```no_run
+    /// # use proxmox_api::model::node::tasks::TasksFilter;
+    /// # async fn example(client: proxmox_api::ProxmoxClient) -> proxmox_api::error::Result<()> {
+    /// # let node = client.node("pve1");
     /// let filter = TasksFilter {
-    ///     only_erros: Some(true),
+    ///     only_errors: Some(true),
     ///     ..Default::default()
     /// };
     ///
-    /// node.tasks(Some(filter));
+    /// node.tasks(Some(filter)).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     pub async fn tasks(
@@ -420,6 +812,87 @@ impl PveNode {
         Ok(PveResponse::from_response(response).await?.data)
     }
 
+    /// Creates a VNC Shell proxy.
+    /// * `cmd` - Run specific command or default to login.
+    /// * `cmd_opts` - Add parameters to a command. Encoded as null terminated strings.
+    pub async fn termproxy(
+        &self,
+        cmd: Option<model::node::console::TermCommand>,
+        cmd_opts: Option<&str>,
+    ) -> Result<model::node::console::TermProxy> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/termproxy", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "cmd": cmd,
+            "cmd-opts": cmd_opts
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Creates a VNC Shell proxy.
+    /// * `cmd` - Run specific command or default to login.
+    /// * `cmd_opts` - Add parameters to a command. Encoded as null terminated strings.
+    /// * `websocket` - Use websocket instead of standard VNC.
+    /// * `width` - Sets the width of the console in pixels.
+    /// * `height` - Sets the height of the console in pixels.
+    pub async fn vncproxy(
+        &self,
+        cmd: Option<model::node::console::TermCommand>,
+        cmd_opts: Option<&str>,
+        websocket: bool,
+        width: Option<u16>,
+        height: Option<u16>,
+    ) -> Result<model::node::console::VncProxy> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/vncshell", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "cmd": cmd,
+            "cmd-opts": cmd_opts,
+            "websocket": websocket,
+            "width": width,
+            "height": height
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Opens a websocket to a termproxy created with [`PveNode::termproxy`].
+    #[cfg(feature = "websocket")]
+    pub async fn vnc_websocket(
+        &self,
+        proxy: &model::node::console::TermProxy,
+    ) -> Result<crate::xterm::XTermStream> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/vncwebsocket", self.id))
+            .expect("Correct URL");
+
+        crate::xterm::XTermStream::connect(&self.client, url, proxy).await
+    }
+
     pub async fn lxcs(&self) -> Result<Vec<model::node::lxc::LXC>> {
         let url = self
             .host
@@ -444,4 +917,13 @@ impl PveNode {
             self.client.clone(),
         )
     }
+
+    pub fn storage(&self, id: &str) -> PveStorage {
+        PveStorage::new(
+            self.id.clone(),
+            id.to_string(),
+            self.host.clone(),
+            self.client.clone(),
+        )
+    }
 }
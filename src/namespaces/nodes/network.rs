@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::model::node::network::{Interface, InterfaceField, InterfaceParameters, TypeFilter};
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Network {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Network {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// List available networks.
+    /// * `type_filter` - Only list specific interface types.
+    pub async fn list(&self, type_filter: Option<TypeFilter>) -> Result<Vec<Interface>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/network", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("type", type_filter)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read network device configuration.
+    pub async fn get(&self, iface: &str) -> Result<Interface> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/network/{}", self.id, iface))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Create network device configuration. The change is pending until [`Network::apply`] is called.
+    pub async fn create(&self, parameters: &InterfaceParameters) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/network", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(parameters)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update network device configuration. The change is pending until [`Network::apply`] is called.
+    /// * `delete` - Settings to remove from the interface.
+    pub async fn update(
+        &self,
+        parameters: &InterfaceParameters,
+        delete: &[InterfaceField],
+    ) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/network/{}",
+                self.id, parameters.iface
+            ))
+            .expect("Correct URL");
+
+        let mut body =
+            serde_json::to_value(parameters).expect("Correct serialization of InterfaceParameters");
+        if let Some(body) = body.as_object_mut() {
+            body.remove("iface");
+
+            if !delete.is_empty() {
+                let delete: Vec<String> = delete.iter().map(|x| x.to_string()).collect();
+                body.insert("delete".into(), delete.join(",").into());
+            }
+        }
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete network device configuration. The change is pending until [`Network::apply`] is called.
+    pub async fn delete(&self, iface: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/network/{}", self.id, iface))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reload network configuration, applying the pending /etc/network/interfaces.new.
+    /// Returns the UPID of the reload task.
+    pub async fn apply(&self) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/network", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .put(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Revert network configuration changes, discarding /etc/network/interfaces.new.
+    pub async fn revert(&self) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/network", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
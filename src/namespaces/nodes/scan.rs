@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use reqwest::{Client, Url};
+
+use crate::model::node::scan::{
+    CifsShare, IscsiTarget, LvmThinPool, LvmVolumeGroup, NfsExport, PbsDatastore, ZfsPool,
+};
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Scan {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Scan {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// Scan remote NFS server.
+    pub async fn nfs(&self, server: &str) -> Result<Vec<NfsExport>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/nfs", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("server", server)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Scan remote CIFS server.
+    /// * `domain` - SMB domain (Workgroup).
+    pub async fn cifs(
+        &self,
+        server: &str,
+        username: Option<&str>,
+        password: Option<&str>,
+        domain: Option<&str>,
+    ) -> Result<Vec<CifsShare>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/cifs", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[
+                ("server", Some(server)),
+                ("username", username),
+                ("password", password),
+                ("domain", domain),
+            ])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Scan remote iSCSI server.
+    /// * `portal` - The iSCSI portal (IP or DNS name with optional port).
+    pub async fn iscsi(&self, portal: &str) -> Result<Vec<IscsiTarget>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/iscsi", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("portal", portal)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List local LVM volume groups.
+    pub async fn lvm(&self) -> Result<Vec<LvmVolumeGroup>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/lvm", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// List local LVM Thin Pools.
+    /// * `vg` - The volume group to scan.
+    pub async fn lvm_thin(&self, vg: &str) -> Result<Vec<LvmThinPool>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/lvmthin", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("vg", vg)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Scan zfs pool list on local node.
+    pub async fn zfs(&self) -> Result<Vec<ZfsPool>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/zfs", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Scan remote Proxmox Backup Server.
+    /// * `username` - User-name or API token-ID.
+    /// * `password` - User password or API token secret.
+    /// * `fingerprint` - Certificate SHA 256 fingerprint.
+    /// * `port` - Optional port, defaults to 8007.
+    pub async fn pbs(
+        &self,
+        server: &str,
+        username: &str,
+        password: &str,
+        fingerprint: Option<&str>,
+        port: Option<u16>,
+    ) -> Result<Vec<PbsDatastore>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/scan/pbs", self.id))
+            .expect("Correct URL");
+
+        let port = port.map(|port| port.to_string());
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[
+                ("server", Some(server)),
+                ("username", Some(username)),
+                ("password", Some(password)),
+                ("fingerprint", fingerprint),
+                ("port", port.as_deref()),
+            ])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use reqwest::{Client, Url};
+
+use crate::model::node::services::{Service, ServiceName};
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Services {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Services {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// Service list.
+    pub async fn list(&self) -> Result<Vec<Service>> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/services", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Read service properties
+    pub async fn state(&self, service: impl Into<ServiceName>) -> Result<Service> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/services/{}/state",
+                self.id,
+                service.into()
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Start service. Returns the UPID of the task.
+    pub async fn start(&self, service: impl Into<ServiceName>) -> Result<String> {
+        self.command(service.into(), "start").await
+    }
+
+    /// Stop service. Returns the UPID of the task.
+    pub async fn stop(&self, service: impl Into<ServiceName>) -> Result<String> {
+        self.command(service.into(), "stop").await
+    }
+
+    /// Hard restart service. Use reload if you want to reduce interruptions. Returns the UPID of the task.
+    pub async fn restart(&self, service: impl Into<ServiceName>) -> Result<String> {
+        self.command(service.into(), "restart").await
+    }
+
+    /// Reload service. Falls back to restart if service cannot be reloaded. Returns the UPID of the task.
+    pub async fn reload(&self, service: impl Into<ServiceName>) -> Result<String> {
+        self.command(service.into(), "reload").await
+    }
+
+    async fn command(&self, service: ServiceName, command: &str) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/services/{}/{}",
+                self.id, service, command
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use reqwest::{Client, Url};
+
+use crate::model::node::storage::{ContentType, StorageContent};
+use crate::model::node::{NodeId, VMId};
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct PveStorage {
+    node_id: NodeId,
+    id: String,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl PveStorage {
+    pub(crate) fn new(node_id: NodeId, id: String, host: Arc<Url>, client: Client) -> Self {
+        Self {
+            node_id,
+            id,
+            host,
+            client,
+        }
+    }
+
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.clone()
+    }
+
+    /// List storage content.
+    /// * `content` - Only list content of this type.
+    /// * `vmid` - Only list images for this VM.
+    pub async fn content(
+        &self,
+        content: Option<ContentType>,
+        vmid: Option<&VMId>,
+    ) -> Result<Vec<StorageContent>> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/storage/{}/content",
+                self.node_id, self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("content", content)])
+            .query(&[("vmid", vmid)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// The most recent container backup of `vmid` on this storage.
+    pub async fn latest_backup(&self, vmid: &VMId) -> Result<Option<StorageContent>> {
+        let contents = self.content(Some(ContentType::Backup), Some(vmid)).await?;
+
+        Ok(crate::model::node::storage::latest_backup(&contents, vmid).cloned())
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use reqwest::{Client, StatusCode, Url};
+
+use crate::model::node::subscription::Subscription as SubscriptionInfo;
+use crate::model::node::NodeId;
+use crate::model::PveResponse;
+
+use crate::error::{ProxmoxAPIError, Result};
+
+#[derive(Clone)]
+pub struct Subscription {
+    id: NodeId,
+    host: Arc<Url>,
+    client: Client,
+}
+
+impl Subscription {
+    pub(crate) fn new(id: NodeId, host: Arc<Url>, client: Client) -> Self {
+        Self { id, host, client }
+    }
+
+    pub fn id(&self) -> NodeId {
+        self.id.clone()
+    }
+
+    /// Read subscription info.
+    pub async fn get(&self) -> Result<SubscriptionInfo> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/subscription", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Set subscription key.
+    pub async fn set(&self, key: &str) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/subscription", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "key": key
+        });
+
+        let response = self
+            .client
+            .put(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update subscription info.
+    /// * `force` - Always connect to server, even if local cache is still valid.
+    pub async fn update(&self, force: bool) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/subscription", self.id))
+            .expect("Correct URL");
+
+        let body = serde_json::json!({
+            "force": force
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delete subscription key of this node.
+    pub async fn delete(&self) -> Result<()> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/subscription", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .delete(url)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        if !response.status().is_success() {
+            match response.status() {
+                StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+                status => return Err(ProxmoxAPIError::ApiError(status)),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use reqwest::{Client, Url};
+use tracing::error;
 
 use crate::model::node::NodeId;
 use crate::model::{self, PveResponse};
@@ -47,4 +48,56 @@ impl VZDump {
 
         Ok(PveResponse::from_response(response).await?.data)
     }
+
+    /// Create backup. Returns the UPID of the backup task.
+    pub async fn backup(
+        &self,
+        options: &model::node::vzdump::backup::BackupOptions,
+    ) -> Result<String> {
+        let url = self
+            .host
+            .join(&format!("/api2/json/nodes/{}/vzdump", self.id))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .post(url)
+            .json(options)
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        Ok(PveResponse::from_response(response).await?.data)
+    }
+
+    /// Extract configuration from vzdump backup archive.
+    /// Container configurations are typed, VM configurations are returned untyped.
+    /// * `volume` - Volume identifier, e.g. `local:backup/vzdump-lxc-100-2024_01_01-00_00_00.tar.zst`.
+    pub async fn extract_config(
+        &self,
+        volume: &str,
+    ) -> Result<model::node::vzdump::extract_config::BackupConfig> {
+        let url = self
+            .host
+            .join(&format!(
+                "/api2/json/nodes/{}/vzdump/extractconfig",
+                self.id
+            ))
+            .expect("Correct URL");
+
+        let response = self
+            .client
+            .get(url)
+            .query(&[("volume", volume)])
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        let config: String = PveResponse::from_response(response).await?.data;
+
+        config.parse().map_err(|err| {
+            error!("{err}");
+            ProxmoxAPIError::DeserializationError
+        })
+    }
 }
@@ -0,0 +1,198 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures_util::{Sink, SinkExt, StreamExt};
+use reqwest::{header, Client, StatusCode, Upgraded, Url};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio_tungstenite::{
+    tungstenite::{handshake::client::generate_key, protocol::Role, Message},
+    WebSocketStream,
+};
+
+use crate::{
+    error::{ProxmoxAPIError, Result},
+    model::node::console::TermProxy,
+};
+
+/// Terminal session opened through `vncwebsocket` on a termproxy.
+///
+/// Reading yields the raw terminal output, writing sends keyboard input.
+/// The xterm.js framing used by Proxmox is handled transparently.
+/// Writes are only queued on the socket, call `flush` to send them.
+pub struct XTermStream {
+    socket: WebSocketStream<Upgraded>,
+    pending: Vec<u8>,
+    offset: usize,
+}
+
+impl XTermStream {
+    pub(crate) async fn connect(client: &Client, mut url: Url, proxy: &TermProxy) -> Result<Self> {
+        url.query_pairs_mut()
+            .append_pair("port", &proxy.port.to_string())
+            .append_pair("vncticket", &proxy.ticket);
+
+        let response = client
+            .get(url)
+            .header(header::CONNECTION, "Upgrade")
+            .header(header::UPGRADE, "websocket")
+            .header(header::SEC_WEBSOCKET_VERSION, "13")
+            .header(header::SEC_WEBSOCKET_KEY, generate_key())
+            .header(header::SEC_WEBSOCKET_PROTOCOL, "binary")
+            .send()
+            .await
+            .map_err(|_| ProxmoxAPIError::NetworkError)?;
+
+        match response.status() {
+            StatusCode::SWITCHING_PROTOCOLS => {}
+            StatusCode::UNAUTHORIZED => return Err(ProxmoxAPIError::Unauthorized),
+            status => return Err(ProxmoxAPIError::ApiError(status)),
+        }
+
+        let upgraded = response
+            .upgrade()
+            .await
+            .map_err(|_| ProxmoxAPIError::WebSocketError)?;
+
+        let mut socket = WebSocketStream::from_raw_socket(upgraded, Role::Client, None).await;
+
+        socket
+            .send(Message::Binary(
+                format!("{}:{}\n", proxy.user, proxy.ticket).into_bytes(),
+            ))
+            .await
+            .map_err(|_| ProxmoxAPIError::WebSocketError)?;
+
+        // The termproxy answers with "OK" once the ticket has been accepted.
+        loop {
+            let data = match socket.next().await {
+                Some(Ok(Message::Binary(data))) => data,
+                Some(Ok(Message::Text(text))) => text.into_bytes(),
+                Some(Ok(Message::Close(_))) => return Err(ProxmoxAPIError::Unauthorized),
+                Some(Ok(_)) => continue,
+                Some(Err(_)) | None => return Err(ProxmoxAPIError::WebSocketError),
+            };
+
+            if !data.starts_with(b"OK") {
+                return Err(ProxmoxAPIError::Unauthorized);
+            }
+
+            return Ok(Self {
+                socket,
+                pending: data,
+                offset: 2,
+            });
+        }
+    }
+
+    /// Resize the remote terminal.
+    pub async fn resize(&mut self, columns: u16, rows: u16) -> Result<()> {
+        self.socket
+            .send(Message::Binary(encode_resize(columns, rows)))
+            .await
+            .map_err(|_| ProxmoxAPIError::WebSocketError)
+    }
+
+    /// Keep the session alive. Proxmox closes idle terminals after a while.
+    pub async fn ping(&mut self) -> Result<()> {
+        self.socket
+            .send(Message::Binary(encode_ping()))
+            .await
+            .map_err(|_| ProxmoxAPIError::WebSocketError)
+    }
+}
+
+impl AsyncRead for XTermStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        while this.offset >= this.pending.len() {
+            match ready!(this.socket.poll_next_unpin(cx)) {
+                Some(Ok(Message::Binary(data))) => this.pending = data,
+                Some(Ok(Message::Text(text))) => this.pending = text.into_bytes(),
+                Some(Ok(Message::Close(_))) | None => return Poll::Ready(Ok(())),
+                Some(Ok(_)) => continue,
+                Some(Err(err)) => return Poll::Ready(Err(io::Error::other(err))),
+            }
+
+            this.offset = 0;
+        }
+
+        let available = &this.pending[this.offset..];
+        let len = available.len().min(buf.remaining());
+
+        buf.put_slice(&available[..len]);
+        this.offset += len;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for XTermStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        ready!(Pin::new(&mut this.socket).poll_ready(cx)).map_err(io::Error::other)?;
+
+        Pin::new(&mut this.socket)
+            .start_send(Message::Binary(encode_data(buf)))
+            .map_err(io::Error::other)?;
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().socket)
+            .poll_flush(cx)
+            .map_err(io::Error::other)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().socket)
+            .poll_close(cx)
+            .map_err(io::Error::other)
+    }
+}
+
+// xterm.js framing: "0:<length>:<data>" for input, "1:<cols>:<rows>:" for resize and "2" for ping
+fn encode_data(data: &[u8]) -> Vec<u8> {
+    let mut frame = format!("0:{}:", data.len()).into_bytes();
+    frame.extend_from_slice(data);
+    frame
+}
+
+fn encode_resize(columns: u16, rows: u16) -> Vec<u8> {
+    format!("1:{columns}:{rows}:").into_bytes()
+}
+
+fn encode_ping() -> Vec<u8> {
+    b"2".to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_data, encode_ping, encode_resize};
+
+    #[test]
+    pub fn test_encode_data() {
+        assert_eq!(encode_data(b"ls -la\n"), b"0:7:ls -la\n");
+        assert_eq!(encode_data("ä".as_bytes()), "0:2:ä".as_bytes());
+        assert_eq!(encode_data(b""), b"0:0:");
+    }
+
+    #[test]
+    pub fn test_encode_control() {
+        assert_eq!(encode_resize(80, 24), b"1:80:24:");
+        assert_eq!(encode_ping(), b"2");
+    }
+}